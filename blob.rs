@@ -1,29 +1,38 @@
 //! Derived from `hb-blob.h`.
 
+use std::fs::File;
+use std::io;
+use std::marker::PhantomData;
 use std::slice;
 use std::ptr;
-use std::ops::Deref;
+use std::ops::{Bound, Deref, DerefMut, RangeBounds};
+use std::path::Path;
+use std::sync::Arc;
 
-use libc;
+use memmap2::Mmap;
 
-use harfbuzz::common::{hb_destroy_func_t, into_user_data};
+use crate::common::{UserDataKey, hb_destroy_func_t, into_user_data};
 
+/// How HarfBuzz may treat the memory a blob is created from, which matters once HarfBuzz wants to
+/// write to it.
+///
 /// Equivalent to `hb_memory_mode_t`
 #[repr(C)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum MemoryMode {
-    /// `HB_MEMORY_MODE_DUPLICATE`
+    /// Copy the data straight away. `HB_MEMORY_MODE_DUPLICATE`
     Duplicate,
-    /// `HB_MEMORY_MODE_READONLY`
+    /// Never write to the data; copy it if writing is needed. `HB_MEMORY_MODE_READONLY`
     Readonly,
-    /// `HB_MEMORY_MODE_WRITABLE`
+    /// The data may be written to in place. `HB_MEMORY_MODE_WRITABLE`
     Writable,
-    /// `HB_MEMORY_MODE_READONLY_MAY_MAKE_WRITABLE`
+    /// The data is read-only, but try to make it writable in place (with `mprotect`) before
+    /// resorting to a copy. `HB_MEMORY_MODE_READONLY_MAY_MAKE_WRITABLE`
     ReadonlyMayMakeWritable,
 }
 
 define_boxed_type! {
-    struct Blob;
+    struct Blob<'a>;
     enum hb_blob_t;
     fn hb_blob_get_empty;
     fn hb_blob_reference;
@@ -34,69 +43,262 @@ define_boxed_type! {
     fn hb_blob_is_immutable;
 }
 
-impl From<&'static [u8]> for Blob {
-    fn from(data: &'static [u8]) -> Blob {
+/// Borrows the data rather than copying it; the blob (and anything made from it) can’t outlive the
+/// borrow.
+impl<'a> From<&'a [u8]> for Blob<'a> {
+    fn from(data: &'a [u8]) -> Blob<'a> {
+        Blob::from_slice(data, MemoryMode::Readonly)
+    }
+}
+
+impl From<Vec<u8>> for Blob<'static> {
+    fn from(data: Vec<u8>) -> Blob<'static> {
+        Blob::from_vec(data, MemoryMode::Readonly)
+    }
+}
+
+/// The `user_data` key under which `Blob::from_owner` keeps the owner of a blob’s bytes, for
+/// `Blob::owner`. It’s private so that nothing else can remove or replace the entry.
+struct BlobOwner<T>(PhantomData<T>);
+
+impl<T> BlobOwner<T> {
+    fn new() -> BlobOwner<T> {
+        BlobOwner(PhantomData)
+    }
+}
+
+impl<T: 'static> UserDataKey for BlobOwner<T> {
+    type Data = Arc<T>;
+}
+
+impl<'a> Blob<'a> {
+    /// Equivalent to `hb_blob_create` for data we may only read.
+    ///
+    /// HarfBuzz mustn’t write through a shared reference, so the writable modes are treated as
+    /// `Readonly` here: should HarfBuzz itself want to write to the data (as its sanitizer may, to
+    /// neuter a broken table), it works on a copy instead.
+    pub fn from_slice(data: &'a [u8], mode: MemoryMode) -> Blob<'a> {
+        let mode = match mode {
+            MemoryMode::Duplicate => MemoryMode::Duplicate,
+            _ => MemoryMode::Readonly,
+        };
+        unsafe {
+            Blob::from(hb_blob_create(data.as_ptr() as *const libc::c_char,
+                                      data.len() as libc::c_uint,
+                                      mode,
+                                      ptr::null_mut(),
+                                      None))
+        }
+    }
+
+    /// Equivalent to `hb_blob_create`. Any mode is fine here, since the data is ours to write to.
+    pub fn from_mut_slice(data: &'a mut [u8], mode: MemoryMode) -> Blob<'a> {
         unsafe {
-            Blob::from(hb_blob_create(data as *const [u8] as *const libc::c_char,
+            Blob::from(hb_blob_create(data.as_mut_ptr() as *const libc::c_char,
                                       data.len() as libc::c_uint,
-                                      MemoryMode::Readonly,
+                                      mode,
                                       ptr::null_mut(),
                                       None))
         }
     }
 }
 
-impl From<Vec<u8>> for Blob {
-    fn from(data: Vec<u8>) -> Blob {
+impl Blob<'static> {
+    /// Equivalent to `hb_blob_create`, with the destroy function dropping the `Vec`.
+    pub fn from_vec(data: Vec<u8>, mode: MemoryMode) -> Blob<'static> {
+        let mut data = data;
         unsafe {
-            let data_ptr = data.as_ptr() as *const libc::c_char;
+            let data_ptr = data.as_mut_ptr() as *const libc::c_char;
             let data_len = data.len() as libc::c_uint;
             let (user_data, destroy) = into_user_data(data);
-            Blob::from(hb_blob_create(data_ptr, data_len, MemoryMode::Readonly, user_data,
-                                      destroy))
+            Blob::from(hb_blob_create(data_ptr, data_len, mode, user_data, destroy))
+        }
+    }
+
+    /// Makes a blob of the bytes in anything that owns some, such as an `Arc<[u8]>` from a font
+    /// cache, a `Box<[u8]>` or a memory map, without copying them. This is the generalisation of
+    /// `From<Vec<u8>>`.
+    ///
+    /// The owner is kept alive until HarfBuzz is done with the blob, and can be had back through
+    /// `owner`, so several faces can share one buffer and still find out what it was.
+    ///
+    /// There is no direct equivalent in HarfBuzz.
+    pub fn from_owner<T>(owner: T) -> Blob<'static>
+    where T: AsRef<[u8]> + Send + Sync + 'static {
+        let owner = Arc::new(owner);
+        let data = (*owner).as_ref();
+        let data_ptr = data.as_ptr() as *const libc::c_char;
+        let data_len = data.len() as libc::c_uint;
+        unsafe {
+            let (user_data, destroy) = into_user_data(owner.clone());
+            let mut blob = Blob::from(hb_blob_create(data_ptr, data_len, MemoryMode::Readonly,
+                                                     user_data, destroy));
+            blob.user_data.set(BlobOwner::<T>::new(), owner, true);
+            blob
+        }
+    }
+
+    /// Memory-maps a file read-only and wraps it in a blob, so that even a very large font can be
+    /// opened without reading it all in. The mapping lives as long as the blob (and any sub-blobs,
+    /// faces, &c. that reference it) and is unmapped when HarfBuzz destroys it.
+    ///
+    /// As with any memory map, the file must not be modified while it is mapped; if it is, what
+    /// HarfBuzz sees is undefined.
+    ///
+    /// There is no direct equivalent in HarfBuzz; `hb_blob_create_from_file` is similar, but
+    /// can’t tell you why it failed.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Blob<'static>> {
+        let file = File::open(path)?;
+        let mmap = unsafe { Mmap::map(&file)? };
+        if mmap.len() > libc::c_uint::MAX as usize {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                      "file is too large for a HarfBuzz blob"));
+        }
+        unsafe {
+            let data_ptr = mmap.as_ptr() as *const libc::c_char;
+            let data_len = mmap.len() as libc::c_uint;
+            let (user_data, destroy) = into_user_data(mmap);
+            Ok(Blob::from(hb_blob_create(data_ptr, data_len, MemoryMode::Readonly, user_data,
+                                         destroy)))
         }
     }
 }
 
-impl Blob {
-    /// Ideally this’d be `blob[start..end]`, but `Index` requires references.
-    pub fn create_sub_blob(&self, offset: u32, length: u32) -> Blob {
+impl<'a> Blob<'a> {
+    /// The owner this blob was made from with `from_owner`, if it was and `T` is its type.
+    ///
+    /// Sub-blobs don’t count: this is only ever set on the blob `from_owner` returned.
+    pub fn owner<T: 'static>(&self) -> Option<Arc<T>> {
+        self.user_data.get(BlobOwner::<T>::new()).cloned()
+    }
+
+    /// Equivalent to `hb_blob_create_sub_blob`: the sub-blob references this one rather than
+    /// copying from it, and an `offset` or `length` past the end is clamped.
+    pub fn create_sub_blob(&self, offset: u32, length: u32) -> Blob<'a> {
         unsafe {
             Blob::from(hb_blob_create_sub_blob(self.ptr, offset, length))
         }
     }
 
+    /// Ideally this’d be `blob[range]`, but `Index` requires references, so here’s the next best
+    /// thing: `blob.slice(4..8)`, `blob.slice(offset..)`, &c. (See also `create_sub_blob`.)
+    ///
+    /// # Panics
+    ///
+    /// As with slicing, if the range is decreasing or extends past the end of the blob.
+    pub fn slice<R: RangeBounds<u32>>(&self, range: R) -> Blob<'a> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("range start overflowed"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("range end overflowed"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        assert!(start <= end, "blob slice starts at {} but ends at {}", start, end);
+        assert!(end <= len, "blob slice end {} is out of range for a blob of length {}", end, len);
+        self.create_sub_blob(start, end - start)
+    }
+
     pub fn len(&self) -> u32 {
         unsafe {
             hb_blob_get_length(self.ptr)
         }
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
-impl Deref for Blob {
+impl<'a> Deref for Blob<'a> {
     type Target = [u8];
 
     fn deref(&self) -> &[u8] {
         unsafe {
             let mut length = 0;
             let data_ptr = hb_blob_get_data(self.ptr, &mut length);
-            slice::from_raw_parts(data_ptr as *const _, length as usize)
+            if data_ptr.is_null() {
+                // The empty blob has no data pointer at all.
+                &[]
+            } else {
+                slice::from_raw_parts(data_ptr as *const _, length as usize)
+            }
         }
     }
 }
 
-impl Blob {
-    pub fn data_mut(&mut self) -> Option<&mut [u8]> {
+impl<'a> Blob<'a> {
+    /// A writable copy of the blob’s data, to be patched in memory (e.g. tweaking `OS/2` fields)
+    /// and then turned back into a blob with `into_blob` to make a `Face` of.
+    ///
+    /// This always copies: a `Blob` is a shared handle, so writing to its data in place would
+    /// change it under every other handle on it, and under any slice of it they’re holding.
+    ///
+    /// Returns `None` if the blob is empty or the copy couldn’t be allocated.
+    ///
+    /// Equivalent to `hb_blob_create` with `HB_MEMORY_MODE_DUPLICATE`, then
+    /// `hb_blob_get_data_writable`.
+    pub fn make_writable(&self) -> Option<WritableBlob> {
         unsafe {
-            let mut length = 0;
-            let data_ptr = hb_blob_get_data_writable(self.ptr, &mut length);
-            if data_ptr.is_null() {
+            let copy = Blob::from(hb_blob_create(hb_blob_get_data(self.ptr, &mut 0),
+                                                 self.len(), MemoryMode::Duplicate,
+                                                 ptr::null_mut(), None));
+            if hb_blob_get_data_writable(copy.ptr, &mut 0).is_null() {
                 None
             } else {
-                Some(slice::from_raw_parts_mut(data_ptr as *mut _, length as usize))
+                Some(WritableBlob(copy))
             }
         }
     }
+
+    #[deprecated(note = "renamed to `make_writable`, which now returns a writable copy")]
+    pub fn data_mut(&self) -> Option<WritableBlob> {
+        self.make_writable()
+    }
+}
+
+/// A blob whose data can be written to, from `Blob::make_writable`.
+///
+/// Unlike a `Blob`, this is the only handle on its blob, so its data can be borrowed mutably; it
+/// can’t be cloned or have sub-blobs or faces made from it until `into_blob` is called.
+///
+/// There is no equivalent in HarfBuzz.
+pub struct WritableBlob(Blob<'static>);
+
+impl WritableBlob {
+    /// Gives up write access, for a blob that can be shared again.
+    pub fn into_blob(self) -> Blob<'static> {
+        self.0
+    }
+}
+
+impl From<WritableBlob> for Blob<'static> {
+    fn from(blob: WritableBlob) -> Blob<'static> {
+        blob.into_blob()
+    }
+}
+
+impl Deref for WritableBlob {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl DerefMut for WritableBlob {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe {
+            // Already writable, as `make_writable` checked, so this doesn’t copy again.
+            let mut length = 0;
+            let data_ptr = hb_blob_get_data_writable(self.0.ptr, &mut length);
+            slice::from_raw_parts_mut(data_ptr as *mut _, length as usize)
+        }
+    }
 }
 
 extern "C" {
@@ -1,20 +1,19 @@
-use harfbuzz::common::{
+use crate::common::{
     hb_var_int_t, Codepoint, Position, Mask, Tag, Language, Script, Direction,
     hb_bool_t,
     LANGUAGE_INVALID,
 };
-use harfbuzz::font::{Font, hb_font_t};
-use harfbuzz::unicode::{UnicodeFuncs, hb_unicode_funcs_t};
+use crate::font::{Font, hb_font_t};
+use crate::unicode::{UnicodeFuncs, hb_unicode_funcs_t};
 
 use std::char;
+use std::convert::Infallible;
 use std::fmt;
 use std::mem;
 use std::ptr;
 use std::slice;
 use std::str::FromStr;
 
-use libc;
-
 define_boxed_type! {
     struct Buffer;
     enum hb_buffer_t;
@@ -107,17 +106,18 @@ impl Buffer {
     }
 
     /// Equivalent to `hb_buffer_set_segment_properties`.
-    /// TODO: why are these two *mut-based?
-    pub fn set_segment_properties(&mut self, props: *mut SegmentProperties) {
+    pub fn set_segment_properties(&mut self, props: &SegmentProperties) {
         unsafe {
             hb_buffer_set_segment_properties(self.ptr, props)
         }
     }
 
     /// Equivalent to `hb_buffer_get_segment_properties`.
-    pub fn segment_properties(&self, props: *mut SegmentProperties) {
+    pub fn segment_properties(&self) -> SegmentProperties {
         unsafe {
-            hb_buffer_get_segment_properties(self.ptr, props)
+            let mut props = SegmentProperties::default();
+            hb_buffer_get_segment_properties(self.ptr, &mut props);
+            props
         }
     }
 
@@ -269,6 +269,10 @@ impl Buffer {
         }
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     // Getting glyphs out of the buffer
 
     /// Equivalent to `hb_buffer_get_glyph_infos` with caveats:
@@ -280,16 +284,28 @@ impl Buffer {
     ///   reversed, it’s just that &self -> &mut anything is not supposed to happen.)
     pub fn glyph_infos(&self) -> &[GlyphInfo] {
         unsafe {
-            let start = hb_buffer_get_glyph_infos(self.ptr, ptr::null_mut());
-            slice::from_raw_parts(start, self.len() as usize)
+            let mut length = 0;
+            let start = hb_buffer_get_glyph_infos(self.ptr, &mut length);
+            if start.is_null() || length == 0 {
+                // An empty buffer may have no array at all.
+                &[]
+            } else {
+                slice::from_raw_parts(start, length as usize)
+            }
         }
     }
 
     /// Equivalent to `hb_buffer_get_glyph_positions` with the same caveats as `glyph_infos`.
     pub fn glyph_positions(&self) -> &[GlyphPosition] {
         unsafe {
-            let start = hb_buffer_get_glyph_positions(self.ptr, ptr::null_mut());
-            slice::from_raw_parts(start, self.len() as usize)
+            let mut length = 0;
+            let start = hb_buffer_get_glyph_positions(self.ptr, &mut length);
+            if start.is_null() || length == 0 {
+                // An empty buffer may have no array at all.
+                &[]
+            } else {
+                slice::from_raw_parts(start, length as usize)
+            }
         }
     }
 
@@ -310,7 +326,7 @@ impl Buffer {
     ///
     /// Equivalent to `hb_buffer_serialize_glyphs`.
     pub fn serialize_glyphs(&mut self, start: u32, end: u32, buf: &mut String,
-                            font: Option<Font>, format: BufferSerializeFormat,
+                            font: Option<Font<'_>>, format: BufferSerializeFormat,
                             flags: BufferSerializeFlags) -> u32 {
         // FIXME: I *believe* it should be legal UTF-8, but what if it’s not? Also using String
         // makes slicing rather difficult because of the absence of &mut str.
@@ -318,7 +334,7 @@ impl Buffer {
             let buf_ptr = buf.as_ptr();
             let mut buf_len = 0;
             let buf_capacity = buf.capacity();
-            mem::forget(mem::replace(buf, String::new()));
+            mem::forget(mem::take(buf));
             let font_ptr = match font {
                 Some(ref font) => font.ptr,
                 None => ptr::null_mut(),
@@ -329,14 +345,13 @@ impl Buffer {
                                                         font_ptr, format, flags);
             // len has now been set to a new value, so we can reconstruct the String now.
             // (If it were a Vec, we could use set_len(), but String doesn’t have that.)
-            mem::replace(buf, String::from_raw_parts(buf_ptr as *mut u8, buf_len as usize,
-                                                     buf_capacity));
+            *buf = String::from_raw_parts(buf_ptr as *mut u8, buf_len as usize, buf_capacity);
             serialized
         }
     }
 
     /// Equivalent to `hb_buffer_serialize_glyphs`.
-    pub fn deserialize_glyphs(&mut self, buf: &str, font: Option<Font>,
+    pub fn deserialize_glyphs(&mut self, buf: &str, font: Option<Font<'_>>,
                               format: BufferSerializeFormat) -> bool {
         unsafe {
             let font_ptr = match font {
@@ -350,6 +365,12 @@ impl Buffer {
     }
 }
 
+impl Default for Buffer {
+    fn default() -> Buffer {
+        Buffer::new()
+    }
+}
+
 /// A convenience technique with no direct equivalent in HarfBuzz.
 impl<'a> From<&'a str> for Buffer {
     fn from(text: &'a str) -> Buffer {
@@ -473,17 +494,17 @@ pub enum BufferContentType {
 bitflags! {
     /// Equivalent to `hb_buffer_serialize_flags_t`.
     #[repr(C)]
-    pub flags BufferSerializeFlags: u32 {
+    pub struct BufferSerializeFlags: u32 {
         /// Equivalent to `HB_BUFFER_SERIALIZE_FLAG_DEFAULT`.
-        const BUFFER_SERIALIZE_FLAG_DEFAULT = 0x00000000,
+        const DEFAULT = 0x00000000;
         /// Equivalent to `HB_BUFFER_SERIALIZE_FLAG_NO_CLUSTERS`.
-        const BUFFER_SERIALIZE_FLAG_NO_CLUSTERS = 0x00000001,
+        const NO_CLUSTERS = 0x00000001;
         /// Equivalent to `HB_BUFFER_SERIALIZE_FLAG_NO_POSITIONS`.
-        const BUFFER_SERIALIZE_FLAG_NO_POSITIONS = 0x00000002,
+        const NO_POSITIONS = 0x00000002;
         /// Equivalent to `HB_BUFFER_SERIALIZE_FLAG_NO_GLYPH_NAMES`.
-        const BUFFER_SERIALIZE_FLAG_NO_GLYPH_NAMES = 0x00000004,
+        const NO_GLYPH_NAMES = 0x00000004;
         /// Equivalent to `HB_BUFFER_SERIALIZE_FLAG_GLYPH_EXTENTS`.
-        const BUFFER_SERIALIZE_FLAG_GLYPH_EXTENTS = 0x00000008,
+        const GLYPH_EXTENTS = 0x00000008;
     }
 }
 
@@ -498,9 +519,9 @@ pub enum BufferSerializeFormat {
 /// Equivalent to `hb_buffer_serialize_format_from_string`.
 impl FromStr for BufferSerializeFormat {
     /// … because errors come through as `BufferSerializeFormat::Invalid` instead.
-    type Err = !;
+    type Err = Infallible;
 
-    fn from_str(str: &str) -> Result<BufferSerializeFormat, !> {
+    fn from_str(str: &str) -> Result<BufferSerializeFormat, Infallible> {
         unsafe {
             Ok(hb_buffer_serialize_format_from_string(str.as_ptr() as *const libc::c_char,
                                                       str.len() as i32))
@@ -527,7 +548,7 @@ impl BufferSerializeFormat {
     pub fn list_formats() -> &'static [&'static str] {
         // Copied from hb-buffer-serialize.cc instead of calling hb_buffer_serialize_list_formats.
         // Yes, this is a slightly risky thing to do, but it should be OK.
-        const FORMATS: &'static [&'static str] = &["text", "json"];
+        const FORMATS: &[&str] = &["text", "json"];
 
         FORMATS
     }
@@ -625,15 +646,15 @@ extern "C" {
 bitflags! {
     /// Equivalent to `hb_buffer_flags_t`.
     #[repr(C)]
-    pub flags BufferFlags: u32 {
+    pub struct BufferFlags: u32 {
         /// Equivalent to `HB_BUFFER_FLAGS_DEFAULT`.
-        const BUFFER_FLAGS_DEFAULT = 0x00000000,
+        const DEFAULT = 0x00000000;
         /// Beginning-of-text. Equivalent to `HB_BUFFER_FLAGS_BOT`.
-        const BUFFER_FLAGS_BOT = 0x00000001,
+        const BOT = 0x00000001;
         /// End-of-text. Equivalent to `HB_BUFFER_FLAGS_EOT`.
-        const BUFFER_FLAGS_EOT = 0x00000002,
+        const EOT = 0x00000002;
         /// Equivalent to `HB_BUFFER_FLAGS_PRESERVE_DEFAULT_IGNORABLES`.
-        const BUFFER_FLAGS_PRESERVE_DEFAULT_IGNORABLES = 0x00000004,
+        const PRESERVE_DEFAULT_IGNORABLES = 0x00000004;
     }
 }
 
@@ -0,0 +1,60 @@
+//! Finds HarfBuzz for us to link against.
+//!
+//! By default we ask pkg-config for a system `libharfbuzz`. With the `vendored` feature we instead
+//! compile the copy of HarfBuzz in `harfbuzz/` (its amalgamated `harfbuzz.cc`, as `harfbuzz-sys`
+//! does), which needs nothing but a C++ compiler and works offline.
+
+/// The oldest HarfBuzz whose API we use all of.
+#[cfg(not(feature = "vendored"))]
+const MIN_VERSION: &str = "7.0.0";
+
+#[cfg(feature = "vendored")]
+fn build_vendored() {
+    use std::env;
+    use std::path::PathBuf;
+
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    let src = manifest_dir.join("harfbuzz").join("src");
+    let target = env::var("TARGET").unwrap();
+
+    let mut build = cc::Build::new();
+    build.cpp(true)
+         .warnings(false)
+         .flag_if_supported("-std=c++11")
+         .flag_if_supported("-fno-exceptions")
+         .flag_if_supported("-fno-rtti")
+         .file(src.join("harfbuzz.cc"));
+    if !target.contains("windows") {
+        build.define("HAVE_PTHREAD", "1");
+    }
+    build.compile("harfbuzz");
+
+    println!("cargo:rerun-if-changed={}", src.display());
+    println!("cargo:include={}", src.display());
+}
+
+#[cfg(not(feature = "vendored"))]
+fn probe_system() {
+    match pkg_config::Config::new().atleast_version(MIN_VERSION).probe("harfbuzz") {
+        Ok(library) => {
+            for path in library.include_paths {
+                println!("cargo:include={}", path.display());
+            }
+        }
+        Err(error) => {
+            panic!("could not find HarfBuzz >= {} with pkg-config: {}\n\
+                    Install the HarfBuzz development files, or enable the `vendored` feature to \
+                    build the bundled copy instead.", MIN_VERSION, error);
+        }
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "vendored")]
+    build_vendored();
+
+    #[cfg(not(feature = "vendored"))]
+    probe_system();
+}
@@ -1,11 +1,11 @@
 //! Derived from `hb-common.h`.
 
+use std::convert::Infallible;
 use std::fmt;
 use std::mem;
 use std::str::{self, FromStr};
 use std::ptr;
-
-use libc;
+use std::sync::{Mutex, PoisonError};
 
 #[allow(non_camel_case_types)]
 pub(super) type hb_bool_t = i32;
@@ -29,7 +29,7 @@ pub(super) type Mask = u32;
 pub(super) type hb_var_int_t = u32;
 
 /// Equivalent to `hb_tag_t`.
-#[derive(Copy, Clone, Default)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(C)]
 pub struct Tag { pub(super) u32: u32 }
 
@@ -80,14 +80,14 @@ pub(super) const TAG_MAX_SIGNED: Tag = Tag { u32: 0x7fffffff };
 // (Frankly that bit of code feels weird and icky.)
 /// This is also not as general as `hb_tag_from_string`, because `&str` can only be valid UTF-8.
 impl FromStr for Tag {
-    type Err = !;
+    type Err = Infallible;
 
-    fn from_str(str: &str) -> Result<Tag, !> {
+    fn from_str(str: &str) -> Result<Tag, Infallible> {
         let bytes = str.as_bytes();
-        Ok(Tag::from([bytes.get(0).map(|&b| b).unwrap_or(b' '),
-                      bytes.get(1).map(|&b| b).unwrap_or(b' '),
-                      bytes.get(2).map(|&b| b).unwrap_or(b' '),
-                      bytes.get(3).map(|&b| b).unwrap_or(b' ')]))
+        Ok(Tag::from([bytes.first().copied().unwrap_or(b' '),
+                      bytes.get(1).copied().unwrap_or(b' '),
+                      bytes.get(2).copied().unwrap_or(b' '),
+                      bytes.get(3).copied().unwrap_or(b' ')]))
     }
 }
 
@@ -126,11 +126,11 @@ pub enum Direction {
 ///
 /// This one is equivalent, barring the UTF-8 requirement on the source string.
 impl FromStr for Direction {
-    type Err = !;
+    type Err = Infallible;
 
-    fn from_str(str: &str) -> Result<Direction, !> {
+    fn from_str(str: &str) -> Result<Direction, Infallible> {
         // As in hb_direction_from_string, match only the first letter.
-        Ok(match str.as_bytes().get(0) {
+        Ok(match str.as_bytes().first() {
             Some(&b'L') | Some(&b'l') => Direction::Ltr,
             Some(&b'R') | Some(&b'r') => Direction::Rtl,
             Some(&b'T') | Some(&b't') => Direction::Ttb,
@@ -160,46 +160,31 @@ impl Direction {
     /// Equivalent to `HB_DIRECTION_IS_VALID(dir)` in HarfBuzz.
     #[inline]
     pub fn is_valid(self) -> bool {
-        match self {
-            Direction::Invalid => false,
-            _ => true,
-        }
+        !matches!(self, Direction::Invalid)
     }
 
     /// Equivalent to `HB_DIRECTION_IS_HORIZONTAL(dir)` in HarfBuzz.
     #[inline]
     pub fn is_horizontal(self) -> bool {
-        match self {
-            Direction::Ltr | Direction::Rtl => true,
-            _ => false,
-        }
+        matches!(self, Direction::Ltr | Direction::Rtl)
     }
 
     /// Equivalent to `HB_DIRECTION_IS_VERTICAL(dir)` in HarfBuzz.
     #[inline]
     pub fn is_vertical(self) -> bool {
-        match self {
-            Direction::Ttb | Direction::Btt => true,
-            _ => false,
-        }
+        matches!(self, Direction::Ttb | Direction::Btt)
     }
 
     /// Equivalent to `HB_DIRECTION_IS_FORWARD(dir)` in HarfBuzz.
     #[inline]
     pub fn is_forward(self) -> bool {
-        match self {
-            Direction::Ltr | Direction::Ttb => true,
-            _ => false,
-        }
+        matches!(self, Direction::Ltr | Direction::Ttb)
     }
 
     /// Equivalent to `HB_DIRECTION_IS_BACKWARD(dir)` in HarfBuzz.
     #[inline]
     pub fn is_backward(self) -> bool {
-        match self {
-            Direction::Rtl | Direction::Btt => true,
-            _ => false,
-        }
+        matches!(self, Direction::Rtl | Direction::Btt)
     }
 
     /// Equivalent to `HB_DIRECTION_REVERSE(dir)` in HarfBuzz.
@@ -233,7 +218,7 @@ pub struct Language(*const LanguageImpl);
 // TODO: impl FromStr and Display via extern fns hb_language_from_string and hb_language_to_string.
 
 /// Equivalent to `HB_LANGUAGE_INVALID`, though that is a macro and this is a constant.
-pub(super) const LANGUAGE_INVALID: Language = Language(0 as *const LanguageImpl);
+pub(super) const LANGUAGE_INVALID: Language = Language(ptr::null());
 
 impl Language {
     /// TODO: should this be a Default impl?
@@ -417,14 +402,171 @@ impl Script {
 }
 
 impl FromStr for Script {
-    type Err = !;
+    type Err = Infallible;
 
-    fn from_str(str: &str) -> Result<Script, !> {
+    fn from_str(str: &str) -> Result<Script, Infallible> {
         str.parse().map(Script::from_iso15924_tag)
     }
 }
 
 
+// Variations
+
+/// A setting for one of a variable font’s axes, such as `wght=650`: the axis tag and the value to
+/// set it to, in the axis’s own (design) units.
+///
+/// Equivalent to `hb_variation_t`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Variation {
+    pub tag: Tag,
+    pub value: f32,
+}
+
+impl Variation {
+    pub fn new(tag: Tag, value: f32) -> Variation {
+        Variation { tag, value }
+    }
+}
+
+/// Parses the same syntax as CSS’s `font-variation-settings`, e.g. `wght=650` or `"wdth" 75`.
+impl FromStr for Variation {
+    type Err = ();
+
+    fn from_str(str: &str) -> Result<Variation, ()> {
+        unsafe {
+            let mut variation = Variation::default();
+            if hb_variation_from_string(str.as_ptr() as *const libc::c_char, str.len() as i32,
+                                        &mut variation) != 0 {
+                Ok(variation)
+            } else {
+                Err(())
+            }
+        }
+    }
+}
+
+/// Formats as `tag=value`, which `FromStr` will take back.
+impl fmt::Display for Variation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // hb_variation_to_string never writes more than 128 bytes, NUL included.
+        let mut buf = [0u8; 128];
+        unsafe {
+            hb_variation_to_string(self as *const Variation as *mut Variation,
+                                   buf.as_mut_ptr() as *mut libc::c_char,
+                                   buf.len() as libc::c_uint);
+        }
+        let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+        f.write_str(&String::from_utf8_lossy(&buf[..len]))
+    }
+}
+
+extern "C" {
+    fn hb_variation_from_string(str: *const libc::c_char, len: libc::c_int,
+                                variation: *mut Variation) -> hb_bool_t;
+    fn hb_variation_to_string(variation: *mut Variation, buf: *mut libc::c_char,
+                              size: libc::c_uint);
+}
+
+
+// Colors
+
+/// A color, as the `CPAL` table and the paint API give them: 8 bits each of red, green, blue and
+/// alpha, the alpha not premultiplied.
+///
+/// Equivalent to `hb_color_t`, which packs them into a `u32` as BGRA, blue in the top byte.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Color(u32);
+
+impl Color {
+    /// Equivalent to `HB_COLOR`, which takes them in the order blue, green, red, alpha.
+    pub fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Color {
+        Color((blue as u32) << 24 | (green as u32) << 16 | (red as u32) << 8 | alpha as u32)
+    }
+
+    /// Equivalent to `hb_color_get_red`.
+    pub fn red(self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// Equivalent to `hb_color_get_green`.
+    pub fn green(self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// Equivalent to `hb_color_get_blue`.
+    pub fn blue(self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    /// Equivalent to `hb_color_get_alpha`.
+    pub fn alpha(self) -> u8 {
+        self.0 as u8
+    }
+}
+
+/// Formats as `#rrggbbaa`, as CSS would have it.
+impl fmt::Debug for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}{:02x}", self.red(), self.green(), self.blue(), self.alpha())
+    }
+}
+
+
+// Arrays
+
+/// Many HarfBuzz getters fill in an array a page at a time: they take a `start_offset`, an
+/// in/out `count` (space available in, items written out) and the array, and return the total
+/// number of items. This fetches the lot into a `Vec`, which is what we want nearly every time.
+///
+/// There is no equivalent in HarfBuzz.
+pub(super) unsafe fn collect_array<T, F>(mut get: F) -> Vec<T>
+where T: Copy + Default,
+      F: FnMut(libc::c_uint, *mut libc::c_uint, *mut T) -> libc::c_uint {
+    let mut count = 0;
+    let total = get(0, &mut count, ptr::null_mut());
+    let mut vec = vec![T::default(); total as usize];
+    count = total;
+    get(0, &mut count, vec.as_mut_ptr());
+    vec.truncate(count as usize);
+    vec
+}
+
+
+// Funcs caches
+
+/// Immutable funcs objects (`DrawFuncs`, `PaintFuncs`) made for particular sink types, so that
+/// drawing or painting glyph after glyph doesn’t make new ones each time. Generic code can’t have
+/// a static per type parameter, so this is one static for them all, keyed by the addresses of the
+/// funcs’ trampolines: all of them, since the compiler may merge identical functions. What goes
+/// in here is never freed, like HarfBuzz’s own static funcs.
+///
+/// There is no equivalent in HarfBuzz.
+pub(super) struct FuncsCache<K, F>(Mutex<Vec<(K, F)>>);
+
+// Only immutable funcs go in here, and HarfBuzz reference-counts atomically, so handles on them
+// can be cloned and used from any thread; the keys are only ever compared.
+unsafe impl<K, F> Sync for FuncsCache<K, F> {}
+
+impl<K: PartialEq, F: Clone> FuncsCache<K, F> {
+    pub(super) const fn new() -> FuncsCache<K, F> {
+        FuncsCache(Mutex::new(Vec::new()))
+    }
+
+    /// The funcs for `key`, made with `make` if this is the first time they’re wanted.
+    pub(super) fn get<M: FnOnce() -> F>(&self, key: K, make: M) -> F {
+        let mut cache = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some((_, funcs)) = cache.iter().find(|(cached, _)| *cached == key) {
+            return funcs.clone();
+        }
+        let funcs = make();
+        cache.push((key, funcs.clone()));
+        funcs
+    }
+}
+
+
 // User data
 
 // TODO: replace pub with pub(super) when I clean up the user data API (scrap the pointless trait).
@@ -452,10 +594,13 @@ pub(super) unsafe fn into_user_data<T>(user_data: T) -> (*mut libc::c_void, hb_d
 
     // Note that we can’t do `as extern "C" fn(*mut libc::c_void)`: “non-scalar cast”.
     // So let’s just transmute instead. What could possibly go wrong?
-    (ptr, Some(mem::transmute(destroy::<T> as unsafe extern "C" fn(*mut T))))
+    (ptr, Some(mem::transmute::<unsafe extern "C" fn(*mut T), extern "C" fn(*mut libc::c_void)>(
+        destroy::<T>)))
 }
 
 use std::any::TypeId;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 /// This doesn’t come directly from a HarfBuzz header file; it’s a higher-level, safe API
 /// replacement for `hb_*_set_user_data` and `hb_*_get_user_data`.
@@ -492,13 +637,22 @@ impl<P: HasUserData> UserData<P> {
     pub fn set<K: UserDataKey>(&mut self, _key: K, data: K::Data, replace: bool) -> bool {
         unsafe {
             let (data, destroy) = into_user_data(data);
-            P::set(self.parent, key::<K>(), data, destroy, replace as hb_bool_t) != 0
+            if P::set(self.parent, key::<K>(), data, destroy, replace as hb_bool_t) != 0 {
+                true
+            } else {
+                // HarfBuzz didn’t take it (e.g. the object is inert), so it’s still ours to drop.
+                if let Some(destroy) = destroy {
+                    destroy(data);
+                }
+                false
+            }
         }
     }
 
     pub fn remove<K: UserDataKey>(&mut self, _key: K) -> bool {
         unsafe {
-            P::set(self.parent, key::<K>(), ptr::null_mut(), None, 0) != 0
+            // HarfBuzz only treats a null datum as removal if `replace` is set.
+            P::set(self.parent, key::<K>(), ptr::null_mut(), None, 1) != 0
         }
     }
 
@@ -527,15 +681,23 @@ pub trait HasUserData {
 }
 
 fn key<T: UserDataKey>() -> *mut hb_user_data_key_t {
-    unsafe {
-        let key = mem::transmute::<TypeId, u64>(TypeId::of::<T>()) as usize;
-        key as *mut hb_user_data_key_t
-    }
+    // TypeId is no longer a u64 we can transmute to, but hashing it gets us back to one.
+    let mut hasher = DefaultHasher::new();
+    TypeId::of::<T>().hash(&mut hasher);
+    hasher.finish() as usize as *mut hb_user_data_key_t
 }
 
+/// Defines a safe wrapper around a reference-counted HarfBuzz object.
+///
+/// Types that borrow font data (`Blob`, and the `Face` and `Font` built on it) are declared with a
+/// lifetime, `struct Blob<'a>;`, which becomes a `PhantomData<&'a [u8]>` so that the borrow
+/// checker stops the backing bytes going away while HarfBuzz may still read them.
+///
+/// Attributes before `struct`, doc comments included, are put on the struct.
 macro_rules! define_boxed_type {
     {
-        struct $Foo:ident;
+        $(#[$attr:meta])*
+        struct $Foo:ident $(<$lt:lifetime>)?;
         enum $hb_foo_t:ident;
         // Alas, concat_idents! is still useless for this sort of thing. (`enum concat_idents!(…)`
         // doesn’t work, `fn concat_idents!(…)` ditto.)
@@ -553,14 +715,16 @@ macro_rules! define_boxed_type {
         #[allow(non_camel_case_types)]
         pub enum $hb_foo_t { }
 
-        pub struct $Foo {
+        $(#[$attr])*
+        pub struct $Foo $(<$lt>)? {
             pub(super) ptr: *mut $hb_foo_t,
             /// Replacement for `hb_foo_get_user_data` and `hb_foo_set_user_data`.
             // This design allows the user to replace the user_data and make it misbehave. Too bad.
-            pub user_data: super::common::UserData<$Foo>,
+            pub user_data: super::common::UserData<$Foo $(<$lt>)?>,
+            marker: ::std::marker::PhantomData<($(&$lt [u8],)?)>,
         }
 
-        impl super::common::HasUserData for $Foo {
+        impl $(<$lt>)? super::common::HasUserData for $Foo $(<$lt>)? {
             type Ptr = $hb_foo_t;
 
             unsafe fn get(ptr: *mut Self::Ptr, key: *mut super::common::hb_user_data_key_t) -> *mut ::libc::c_void {
@@ -573,24 +737,25 @@ macro_rules! define_boxed_type {
             }
         }
 
-        impl ::std::fmt::Pointer for $Foo {
+        impl $(<$lt>)? ::std::fmt::Pointer for $Foo $(<$lt>)? {
             fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
                 ::std::fmt::Pointer::fmt(&self.ptr, f)
             }
         }
 
-        impl From<*mut $hb_foo_t> for $Foo {
-            fn from(raw: *mut $hb_foo_t) -> $Foo {
+        impl $(<$lt>)? From<*mut $hb_foo_t> for $Foo $(<$lt>)? {
+            fn from(raw: *mut $hb_foo_t) -> Self {
                 use super::common::UserData;
                 $Foo {
                     ptr: raw,
                     user_data: UserData::from(raw),
+                    marker: ::std::marker::PhantomData,
                 }
             }
         }
 
-        impl $Foo {
-            pub fn get_empty() -> $Foo {
+        impl $(<$lt>)? $Foo $(<$lt>)? {
+            pub fn get_empty() -> Self {
                 unsafe {
                     $Foo::from($hb_foo_get_empty())
                 }
@@ -612,13 +777,13 @@ macro_rules! define_boxed_type {
 
         }
 
-        impl Clone for $Foo {
+        impl $(<$lt>)? Clone for $Foo $(<$lt>)? {
             fn clone(&self) -> Self {
                 $Foo::from(unsafe { $hb_foo_reference(self.ptr) })
             }
         }
 
-        impl Drop for $Foo {
+        impl $(<$lt>)? Drop for $Foo $(<$lt>)? {
             fn drop(&mut self) {
                 unsafe {
                     $hb_foo_destroy(self.ptr);
@@ -0,0 +1,223 @@
+//! Derived from `hb-draw.h`.
+
+use std::ptr;
+
+use crate::common::{Codepoint, FuncsCache, hb_destroy_func_t};
+use crate::font::{Font, hb_font_t};
+
+define_boxed_type! {
+    struct DrawFuncs;
+    enum hb_draw_funcs_t;
+    fn hb_draw_funcs_get_empty;
+    fn hb_draw_funcs_reference;
+    fn hb_draw_funcs_destroy;
+    fn hb_draw_funcs_set_user_data;
+    fn hb_draw_funcs_get_user_data;
+    fn hb_draw_funcs_make_immutable;
+    fn hb_draw_funcs_is_immutable;
+}
+
+/// Something glyph outlines can be drawn into, such as a path builder or a rasterizer: see
+/// `Font::draw_glyph`.
+///
+/// Coordinates are in the font’s scale (`Font::scale`), with y growing upwards. Each contour
+/// starts with `move_to` and ends with `close_path`; quadratic curves (from `glyf`) stay
+/// quadratic, and cubic ones (from `CFF`) stay cubic.
+///
+/// HarfBuzz calls these from C, so they mustn’t panic.
+///
+/// The Rust side of `hb_draw_funcs_t`.
+pub trait DrawSink {
+    fn move_to(&mut self, to_x: f32, to_y: f32);
+    fn line_to(&mut self, to_x: f32, to_y: f32);
+    fn quad_to(&mut self, control_x: f32, control_y: f32, to_x: f32, to_y: f32);
+    fn cubic_to(&mut self, control1_x: f32, control1_y: f32, control2_x: f32, control2_y: f32,
+                to_x: f32, to_y: f32);
+    fn close_path(&mut self);
+}
+
+/// One step of a glyph outline, as drawn into a `Vec<PathCommand>`.
+///
+/// There is no equivalent in HarfBuzz.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PathCommand {
+    MoveTo { to_x: f32, to_y: f32 },
+    LineTo { to_x: f32, to_y: f32 },
+    QuadTo { control_x: f32, control_y: f32, to_x: f32, to_y: f32 },
+    CubicTo {
+        control1_x: f32,
+        control1_y: f32,
+        control2_x: f32,
+        control2_y: f32,
+        to_x: f32,
+        to_y: f32,
+    },
+    ClosePath,
+}
+
+/// Collects the outline as it is drawn, so that `font.draw_glyph(glyph, &mut path)` leaves it in
+/// `path`.
+impl DrawSink for Vec<PathCommand> {
+    fn move_to(&mut self, to_x: f32, to_y: f32) {
+        self.push(PathCommand::MoveTo { to_x, to_y });
+    }
+
+    fn line_to(&mut self, to_x: f32, to_y: f32) {
+        self.push(PathCommand::LineTo { to_x, to_y });
+    }
+
+    fn quad_to(&mut self, control_x: f32, control_y: f32, to_x: f32, to_y: f32) {
+        self.push(PathCommand::QuadTo { control_x, control_y, to_x, to_y });
+    }
+
+    fn cubic_to(&mut self, control1_x: f32, control1_y: f32, control2_x: f32, control2_y: f32,
+                to_x: f32, to_y: f32) {
+        self.push(PathCommand::CubicTo { control1_x, control1_y, control2_x, control2_y,
+                                         to_x, to_y });
+    }
+
+    fn close_path(&mut self) {
+        self.push(PathCommand::ClosePath);
+    }
+}
+
+impl<'a> Font<'a> {
+    /// Draws the glyph’s outline into `sink`, at the font’s scale and with its variation
+    /// coordinates (and synthetic slant or emboldening) applied. Draws nothing if the glyph has no
+    /// outline, e.g. a space, or a glyph that is only a bitmap.
+    ///
+    /// Equivalent to `hb_font_draw_glyph`.
+    pub fn draw_glyph<S: DrawSink>(&self, glyph: Codepoint, sink: &mut S) {
+        unsafe {
+            let dfuncs = DrawFuncs::for_sink::<S>();
+            hb_font_draw_glyph(self.ptr, glyph, dfuncs.ptr, sink as *mut S as *mut libc::c_void);
+        }
+    }
+}
+
+static DRAW_FUNCS: FuncsCache<[*const (); 5], DrawFuncs> = FuncsCache::new();
+
+impl DrawFuncs {
+    /// An immutable `DrawFuncs` whose every func calls through to the `S` passed as draw data,
+    /// made the first time it’s wanted for `S` and cached after that.
+    unsafe fn for_sink<S: DrawSink>() -> DrawFuncs {
+        let key = [move_to_func::<S> as *const (), line_to_func::<S> as *const (),
+                   quadratic_to_func::<S> as *const (), cubic_to_func::<S> as *const (),
+                   close_path_func::<S> as *const ()];
+        DRAW_FUNCS.get(key, || DrawFuncs::create_for_sink::<S>())
+    }
+
+    unsafe fn create_for_sink<S: DrawSink>() -> DrawFuncs {
+        let mut dfuncs = DrawFuncs::from(hb_draw_funcs_create());
+        let p = dfuncs.ptr;
+        let null = ptr::null_mut();
+        hb_draw_funcs_set_move_to_func(p, Some(move_to_func::<S>), null, None);
+        hb_draw_funcs_set_line_to_func(p, Some(line_to_func::<S>), null, None);
+        hb_draw_funcs_set_quadratic_to_func(p, Some(quadratic_to_func::<S>), null, None);
+        hb_draw_funcs_set_cubic_to_func(p, Some(cubic_to_func::<S>), null, None);
+        hb_draw_funcs_set_close_path_func(p, Some(close_path_func::<S>), null, None);
+        dfuncs.make_immutable();
+        dfuncs
+    }
+}
+
+// The funcs `DrawFuncs::for_sink` installs.
+
+fn sink<'s, S>(draw_data: *mut libc::c_void) -> &'s mut S {
+    unsafe { &mut *(draw_data as *mut S) }
+}
+
+extern "C" fn move_to_func<S: DrawSink>(_dfuncs: *mut hb_draw_funcs_t, draw_data: *mut libc::c_void,
+                                        _st: *mut hb_draw_state_t, to_x: f32, to_y: f32,
+                                        _user_data: *mut libc::c_void) {
+    sink::<S>(draw_data).move_to(to_x, to_y)
+}
+
+extern "C" fn line_to_func<S: DrawSink>(_dfuncs: *mut hb_draw_funcs_t, draw_data: *mut libc::c_void,
+                                        _st: *mut hb_draw_state_t, to_x: f32, to_y: f32,
+                                        _user_data: *mut libc::c_void) {
+    sink::<S>(draw_data).line_to(to_x, to_y)
+}
+
+extern "C" fn quadratic_to_func<S: DrawSink>(_dfuncs: *mut hb_draw_funcs_t,
+                                             draw_data: *mut libc::c_void,
+                                             _st: *mut hb_draw_state_t,
+                                             control_x: f32, control_y: f32,
+                                             to_x: f32, to_y: f32,
+                                             _user_data: *mut libc::c_void) {
+    sink::<S>(draw_data).quad_to(control_x, control_y, to_x, to_y)
+}
+
+extern "C" fn cubic_to_func<S: DrawSink>(_dfuncs: *mut hb_draw_funcs_t,
+                                         draw_data: *mut libc::c_void,
+                                         _st: *mut hb_draw_state_t,
+                                         control1_x: f32, control1_y: f32,
+                                         control2_x: f32, control2_y: f32,
+                                         to_x: f32, to_y: f32,
+                                         _user_data: *mut libc::c_void) {
+    sink::<S>(draw_data).cubic_to(control1_x, control1_y, control2_x, control2_y, to_x, to_y)
+}
+
+extern "C" fn close_path_func<S: DrawSink>(_dfuncs: *mut hb_draw_funcs_t,
+                                           draw_data: *mut libc::c_void,
+                                           _st: *mut hb_draw_state_t,
+                                           _user_data: *mut libc::c_void) {
+    sink::<S>(draw_data).close_path()
+}
+
+// FFI:
+
+/// `hb_draw_state_t`, which we leave to HarfBuzz: `DrawSink`s keep track of their own state.
+enum hb_draw_state_t { }
+
+type hb_draw_to_func_t = Option<extern "C" fn(dfuncs: *mut hb_draw_funcs_t,
+                                              draw_data: *mut libc::c_void,
+                                              st: *mut hb_draw_state_t,
+                                              to_x: f32, to_y: f32,
+                                              user_data: *mut libc::c_void)>;
+type hb_draw_move_to_func_t = hb_draw_to_func_t;
+type hb_draw_line_to_func_t = hb_draw_to_func_t;
+type hb_draw_quadratic_to_func_t = Option<extern "C" fn(dfuncs: *mut hb_draw_funcs_t,
+                                                        draw_data: *mut libc::c_void,
+                                                        st: *mut hb_draw_state_t,
+                                                        control_x: f32, control_y: f32,
+                                                        to_x: f32, to_y: f32,
+                                                        user_data: *mut libc::c_void)>;
+type hb_draw_cubic_to_func_t = Option<extern "C" fn(dfuncs: *mut hb_draw_funcs_t,
+                                                    draw_data: *mut libc::c_void,
+                                                    st: *mut hb_draw_state_t,
+                                                    control1_x: f32, control1_y: f32,
+                                                    control2_x: f32, control2_y: f32,
+                                                    to_x: f32, to_y: f32,
+                                                    user_data: *mut libc::c_void)>;
+type hb_draw_close_path_func_t = Option<extern "C" fn(dfuncs: *mut hb_draw_funcs_t,
+                                                      draw_data: *mut libc::c_void,
+                                                      st: *mut hb_draw_state_t,
+                                                      user_data: *mut libc::c_void)>;
+
+extern "C" {
+    fn hb_draw_funcs_create() -> *mut hb_draw_funcs_t;
+    fn hb_draw_funcs_set_move_to_func(dfuncs: *mut hb_draw_funcs_t,
+                                      func: hb_draw_move_to_func_t,
+                                      user_data: *mut libc::c_void,
+                                      destroy: hb_destroy_func_t);
+    fn hb_draw_funcs_set_line_to_func(dfuncs: *mut hb_draw_funcs_t,
+                                      func: hb_draw_line_to_func_t,
+                                      user_data: *mut libc::c_void,
+                                      destroy: hb_destroy_func_t);
+    fn hb_draw_funcs_set_quadratic_to_func(dfuncs: *mut hb_draw_funcs_t,
+                                           func: hb_draw_quadratic_to_func_t,
+                                           user_data: *mut libc::c_void,
+                                           destroy: hb_destroy_func_t);
+    fn hb_draw_funcs_set_cubic_to_func(dfuncs: *mut hb_draw_funcs_t,
+                                       func: hb_draw_cubic_to_func_t,
+                                       user_data: *mut libc::c_void,
+                                       destroy: hb_destroy_func_t);
+    fn hb_draw_funcs_set_close_path_func(dfuncs: *mut hb_draw_funcs_t,
+                                         func: hb_draw_close_path_func_t,
+                                         user_data: *mut libc::c_void,
+                                         destroy: hb_destroy_func_t);
+
+    fn hb_font_draw_glyph(font: *mut hb_font_t, glyph: Codepoint,
+                          dfuncs: *mut hb_draw_funcs_t, draw_data: *mut libc::c_void);
+}
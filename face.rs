@@ -1,12 +1,15 @@
 //! Derived from `hb-face.h`.
 
-use libc;
+use std::io;
+use std::mem;
+use std::path::Path;
+use std::vec;
 
-use harfbuzz::blob::{Blob, hb_blob_t};
-use harfbuzz::common::{Tag, hb_destroy_func_t};
+use crate::blob::{Blob, hb_blob_t};
+use crate::common::{Tag, hb_bool_t, TAG_NONE, collect_array, hb_destroy_func_t, into_user_data};
 
 define_boxed_type! {
-    struct Face;
+    struct Face<'a>;
     enum hb_face_t;
     fn hb_face_get_empty;
     fn hb_face_reference;
@@ -17,38 +20,67 @@ define_boxed_type! {
     fn hb_face_is_immutable;
 }
 
-impl Face {
-    pub fn new(blob: Blob, index: u32) -> Face {
+impl<'a> Face<'a> {
+    /// The face borrows whatever the blob borrows.
+    pub fn new(blob: Blob<'a>, index: u32) -> Face<'a> {
         unsafe {
             Face::from(hb_face_create(blob.ptr, index))
         }
     }
 
-    /*
-    // XXX: I’m not by any means certain this is perfect. I don’t need it myself, see.
-    pub fn create_for_tables<T>(reference_table_func: extern "C" fn(face: *mut hb_face_t, tag: Tag, user_data: *mut T) -> Blob,
-                                user_data: T) -> Face {
-        // We want an fn(&mut Face, Tag, T) -> Blob.
-        // It wants an extern fn(*mut hb_face_t, Tag, *mut libc::c_void) -> *mut hb_blob_t.
-        // TODO.
+    /// The number of faces in a blob: more than one for a font collection (TTC, OTC or DFONT),
+    /// one for a plain font file, and zero if it’s not a font at all.
+    ///
+    /// Equivalent to `hb_face_count`.
+    pub fn count(blob: &Blob<'_>) -> u32 {
         unsafe {
-            let (user_data, destroy) = into_user_data(user_data);
-            Face::from(hb_face_create_for_tables(
-                Some((|face, tag, user_data| reference_table_func(face, tag, user_data).ptr)
-                     as hb_reference_table_func_t__inner),
-                user_data,
-                destroy))
+            hb_face_count(blob.ptr)
+        }
+    }
+
+    /// Memory-maps the font file at `path` (see `Blob::from_file`) and creates a face for the
+    /// `index`th font in it (which is only interesting for collections; otherwise use 0).
+    pub fn from_path<P: AsRef<Path>>(path: P, index: u32) -> io::Result<Face<'static>> {
+        Blob::from_file(path).map(|blob| Face::new(blob, index))
+    }
+
+    /// Creates a face whose tables come from a closure rather than from an sfnt file, for fonts
+    /// kept in some other container (say, WOFF2-decoded tables in a `HashMap`). HarfBuzz calls
+    /// `reference_table` whenever it wants a table, possibly from more than one thread at once;
+    /// returning `None` means there is no such table.
+    ///
+    /// The closure is dropped when the face is destroyed. A panic inside it will abort, since it
+    /// can’t unwind through HarfBuzz.
+    ///
+    /// Equivalent to `hb_face_create_for_tables`.
+    pub fn from_table_fn<F>(reference_table: F) -> Face<'static>
+    where F: Fn(Tag) -> Option<Blob<'static>> + Send + Sync + 'static {
+        extern "C" fn reference_table_func<F>(_face: *mut hb_face_t, tag: Tag,
+                                              user_data: *mut libc::c_void) -> *mut hb_blob_t
+        where F: Fn(Tag) -> Option<Blob<'static>> {
+            let reference_table = unsafe { &*(user_data as *const F) };
+            let blob = reference_table(tag).unwrap_or_else(Blob::get_empty);
+            // HarfBuzz takes ownership of the reference we return.
+            let ptr = blob.ptr;
+            mem::forget(blob);
+            ptr
         }
-    }*/
+
+        unsafe {
+            let (user_data, destroy) = into_user_data(reference_table);
+            Face::from(hb_face_create_for_tables(Some(reference_table_func::<F>), user_data,
+                                                 destroy))
+        }
+    }
 
     // XXX: should these be &self or &mut self?
-    pub fn table(&self, tag: Tag) -> Blob {
+    pub fn table(&self, tag: Tag) -> Blob<'a> {
         unsafe {
             Blob::from(hb_face_reference_table(self.ptr, tag))
         }
     }
 
-    pub fn blob(&self) -> Blob {
+    pub fn blob(&self) -> Blob<'a> {
         unsafe {
             Blob::from(hb_face_reference_blob(self.ptr))
         }
@@ -90,8 +122,159 @@ impl Face {
         }
     }
 
+    /// The tags of the tables in the face’s table directory, in directory order (which is
+    /// normally sorted by tag).
+    ///
+    /// This is always empty for faces made with `from_table_fn`, which have no directory.
+    ///
+    /// Equivalent to `hb_face_get_table_tags`.
+    pub fn table_tags(&self) -> vec::IntoIter<Tag> {
+        unsafe {
+            collect_array(|start_offset, table_count, table_tags| {
+                hb_face_get_table_tags(self.ptr, start_offset, table_count, table_tags)
+            }).into_iter()
+        }
+    }
+
+    /// Whether the face has a `tag` table: it’s in the table directory, or (for faces without
+    /// one) `table` gives something other than the empty blob.
+    ///
+    /// There is no direct equivalent in HarfBuzz.
+    pub fn has_table(&self, tag: Tag) -> bool {
+        // A page of the directory at a time, rather than collecting it all as `table_tags` does.
+        let mut page = [Tag::default(); 32];
+        let mut start_offset = 0;
+        loop {
+            let mut count = page.len() as libc::c_uint;
+            let total = unsafe {
+                hb_face_get_table_tags(self.ptr, start_offset, &mut count, page.as_mut_ptr())
+            };
+            if page[..count as usize].contains(&tag) {
+                return true;
+            }
+            start_offset += count;
+            if count == 0 || start_offset >= total {
+                return total == 0 && !self.table(tag).is_empty();
+            }
+        }
+    }
+}
+
+/// Assembles a new font from tables, e.g. to generate a test font or to patch an existing one: add
+/// the tables you want, then serialize them to an sfnt file with `to_blob` or go straight to a
+/// `Face` with `to_face`.
+///
+/// Wraps `hb_face_builder_create` and friends; in HarfBuzz the builder is itself a special face.
+pub struct FaceBuilder<'a> {
+    face: Face<'a>,
+}
+
+impl<'a> FaceBuilder<'a> {
+    /// Equivalent to `hb_face_builder_create`.
+    pub fn new() -> FaceBuilder<'a> {
+        unsafe {
+            FaceBuilder {
+                face: Face::from(hb_face_builder_create()),
+            }
+        }
+    }
+
+    /// Adds a table, replacing any earlier one with the same tag. The builder references the
+    /// blob rather than copying it.
+    ///
+    /// Returns false if HarfBuzz couldn’t add it (allocation failure, or the tag `0xFFFFFFFF`).
+    ///
+    /// Equivalent to `hb_face_builder_add_table`.
+    pub fn add_table(&mut self, tag: Tag, blob: Blob<'a>) -> bool {
+        unsafe {
+            hb_face_builder_add_table(self.face.ptr, tag, blob.ptr) != 0
+        }
+    }
+
+    /// Sets the order tables are serialized in: those in `tags` first, in that order, then any
+    /// others in HarfBuzz’s default order.
+    ///
+    /// Equivalent to `hb_face_builder_sort_tables`.
+    pub fn sort_tables(&mut self, tags: &[Tag]) {
+        // HarfBuzz wants a list terminated by HB_TAG_NONE.
+        let tags: Vec<Tag> = tags.iter().copied().chain(Some(TAG_NONE)).collect();
+        unsafe {
+            hb_face_builder_sort_tables(self.face.ptr, tags.as_ptr())
+        }
+    }
+
+    /// Serializes the tables added so far into an sfnt (OpenType/TrueType) font file.
+    pub fn to_blob(&self) -> Blob<'a> {
+        self.face.blob()
+    }
+
+    /// Serializes the tables added so far and makes a face of the result.
+    pub fn to_face(&self) -> Face<'a> {
+        Face::new(self.to_blob(), 0)
+    }
+}
+
+impl<'a> Default for FaceBuilder<'a> {
+    fn default() -> FaceBuilder<'a> {
+        FaceBuilder::new()
+    }
+}
+
+impl<'a> Extend<(Tag, Blob<'a>)> for FaceBuilder<'a> {
+    fn extend<I: IntoIterator<Item = (Tag, Blob<'a>)>>(&mut self, iter: I) {
+        for (tag, blob) in iter {
+            self.add_table(tag, blob);
+        }
+    }
+}
+
+impl<'a> FromIterator<(Tag, Blob<'a>)> for FaceBuilder<'a> {
+    fn from_iter<I: IntoIterator<Item = (Tag, Blob<'a>)>>(iter: I) -> FaceBuilder<'a> {
+        let mut builder = FaceBuilder::new();
+        builder.extend(iter);
+        builder
+    }
 }
 
+impl<'a> Blob<'a> {
+    /// Iterates over each face in the blob with its index (see `Face::count`).
+    pub fn faces(&self) -> Faces<'a> {
+        Faces {
+            blob: self.clone(),
+            index: 0,
+            count: Face::count(self),
+        }
+    }
+}
+
+/// An iterator over the faces in a font collection, from `Blob::faces`.
+pub struct Faces<'a> {
+    blob: Blob<'a>,
+    index: u32,
+    count: u32,
+}
+
+impl<'a> Iterator for Faces<'a> {
+    type Item = (u32, Face<'a>);
+
+    fn next(&mut self) -> Option<(u32, Face<'a>)> {
+        if self.index < self.count {
+            let index = self.index;
+            self.index += 1;
+            Some((index, Face::new(self.blob.clone(), index)))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.count - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for Faces<'a> {}
+
 // FFI:
 
 type hb_reference_table_func_t__inner = extern "C" fn(face: *mut hb_face_t, tag: Tag,
@@ -99,6 +282,7 @@ type hb_reference_table_func_t__inner = extern "C" fn(face: *mut hb_face_t, tag:
                                                    -> *mut hb_blob_t;
 type hb_reference_table_func_t = Option<hb_reference_table_func_t__inner>;
 extern "C" {
+    fn hb_face_count(blob: *mut hb_blob_t) -> libc::c_uint;
     fn hb_face_create(blob: *mut hb_blob_t, index: libc::c_uint) -> *mut hb_face_t;
     fn hb_face_create_for_tables(reference_table_func: hb_reference_table_func_t,
                                  user_data: *mut libc::c_void,
@@ -111,4 +295,12 @@ extern "C" {
     fn hb_face_get_upem(face: *mut hb_face_t) -> libc::c_uint;
     fn hb_face_set_glyph_count(face: *mut hb_face_t, glyph_count: libc::c_uint);
     fn hb_face_get_glyph_count(face: *mut hb_face_t) -> libc::c_uint;
+    fn hb_face_builder_create() -> *mut hb_face_t;
+    fn hb_face_builder_add_table(face: *mut hb_face_t, tag: Tag,
+                                 blob: *mut hb_blob_t) -> hb_bool_t;
+    fn hb_face_builder_sort_tables(face: *mut hb_face_t,
+                                   tags: *const Tag /* TAG_NONE-terminated */);
+    fn hb_face_get_table_tags(face: *mut hb_face_t, start_offset: libc::c_uint,
+                              table_count: *mut libc::c_uint /* in/out */,
+                              table_tags: *mut Tag /* out */) -> libc::c_uint;
 }
@@ -1,12 +1,20 @@
 //! Derived from `hb-font.h`.
 
-use libc;
+use std::cell::Cell;
+use std::cmp;
+use std::ffi::CStr;
+use std::mem::{self, ManuallyDrop};
+use std::ptr;
+use std::slice;
+use std::str;
+use std::thread::LocalKey;
 
-use harfbuzz::face::{Face, hb_face_t};
-use harfbuzz::common::{Position, Direction, Codepoint, hb_bool_t, hb_destroy_func_t};
+use crate::face::{Face, hb_face_t};
+use crate::common::{Position, Direction, Codepoint, Variation, hb_bool_t, hb_destroy_func_t,
+                    into_user_data};
 
 define_boxed_type! {
-    struct Font;
+    struct Font<'a>;
     enum hb_font_t;
     fn hb_font_get_empty;
     fn hb_font_reference;
@@ -17,49 +25,51 @@ define_boxed_type! {
     fn hb_font_is_immutable;
 }
 
-impl Font {
-    pub fn new(face: Face) -> Font {
+impl<'a> Font<'a> {
+    /// The font borrows whatever the face borrows.
+    pub fn new(face: Face<'a>) -> Font<'a> {
         unsafe {
             Font::from(hb_font_create(face.ptr))
         }
     }
 
-    pub fn create_sub_font(&self) -> Font {
+    pub fn create_sub_font(&self) -> Font<'a> {
         unsafe {
             Font::from(hb_font_create_sub_font(self.ptr))
         }
     }
 
-    pub fn parent(&self) -> Font {
+    pub fn parent(&self) -> Font<'a> {
         unsafe {
-            Font::from(hb_font_get_parent(self.ptr))
+            // hb_font_get_parent doesn’t give us a reference of our own, so take one.
+            (*ManuallyDrop::new(Font::from(hb_font_get_parent(self.ptr)))).clone()
         }
     }
 
-    pub fn face(&self) -> Face {
+    pub fn face(&self) -> Face<'a> {
         unsafe {
-            Face::from(hb_font_get_face(self.ptr))
+            // Nor does hb_font_get_face.
+            (*ManuallyDrop::new(Face::from(hb_font_get_face(self.ptr)))).clone()
         }
     }
 
-    /*pub unsafe fn set_funcs<T>(&mut self, class: FontFuncs, font_data: T) {
-        let font_data = Some(font_data);
-        let font_data_ptr = font_data.as_mut().map(|x| x as *mut _).unwrap_or(ptr::null_mut());
-        let destroy = || {
-            font_data.take();
-        };
-        hb_font_set_funcs(self.ptr, class.ptr, font_data_ptr, Some(destroy as hb_destroy_func_t__fn))
+    /// Installs `funcs` as the source of this font’s glyph information, in place of whatever it had
+    /// (for a font from `Font::new`, HarfBuzz’s own OpenType implementation).
+    ///
+    /// HarfBuzz keeps `funcs` alive for as long as the font uses it, dropping it when the font is
+    /// destroyed or has something else installed. Installing on a font from `create_sub_font` lets
+    /// the `FontFuncsImpl` methods you don’t override fall back to the parent font.
+    ///
+    /// Equivalent to `hb_font_set_funcs`.
+    pub fn set_funcs<T>(&mut self, funcs: T)
+    where T: FontFuncsImpl + Send + Sync + 'static {
+        unsafe {
+            let klass = FontFuncs::for_impl::<T>();
+            let (font_data, destroy) = into_user_data(funcs);
+            hb_font_set_funcs(self.ptr, klass.ptr, font_data, destroy);
+        }
     }
 
-    pub unsafe fn set_funcs_data<T>(&mut self, font_data: T) {
-        let font_data = Some(font_data);
-        let font_data_ptr = font_data.as_mut().map(|x| x as *mut _).unwrap_or(ptr::null_mut());
-        let destroy = || {
-            font_data.take();
-        };
-        hb_font_set_funcs_data(self.ptr, font_data_ptr, Some(destroy as hb_destroy_func_t__fn))
-    }*/
-
     pub fn scale(&self) -> (i32, i32) {
         let mut x_scale = 0;
         let mut y_scale = 0;
@@ -89,9 +99,87 @@ impl Font {
             hb_font_set_ppem(self.ptr, x_ppem, y_ppem);
         }
     }
+
+    /// Sets the variable font’s axes, in design units (e.g. `wght=650`). Axes not mentioned go
+    /// back to their defaults (or to the named instance’s values, after `set_named_instance`), and
+    /// ones the face doesn’t have are ignored.
+    ///
+    /// Equivalent to `hb_font_set_variations`.
+    pub fn set_variations(&self, variations: &[Variation]) {
+        unsafe {
+            hb_font_set_variations(self.ptr, variations.as_ptr(), variations.len() as libc::c_uint);
+        }
+    }
+
+    /// Sets the variable font’s axes directly in normalized coordinates, one per axis in the order
+    /// of the `fvar` table: 2.14 fixed-point numbers, −1.0 to 1.0 being −16384 to 16384, with the
+    /// axis default at 0.
+    ///
+    /// Equivalent to `hb_font_set_var_coords_normalized`.
+    pub fn set_var_coords_normalized(&self, coords: &[i32]) {
+        unsafe {
+            hb_font_set_var_coords_normalized(self.ptr, coords.as_ptr(),
+                                              coords.len() as libc::c_uint);
+        }
+    }
+
+    /// The font’s normalized coordinates, however they were set (see `set_var_coords_normalized`).
+    /// Empty if the font is at its default instance.
+    ///
+    /// Equivalent to `hb_font_get_var_coords_normalized`.
+    pub fn var_coords_normalized(&self) -> Vec<i32> {
+        unsafe {
+            let mut length = 0;
+            let coords = hb_font_get_var_coords_normalized(self.ptr, &mut length);
+            if coords.is_null() {
+                Vec::new()
+            } else {
+                // Copied, as setting the coordinates through any handle on the font frees these.
+                slice::from_raw_parts(coords, length as usize).to_vec()
+            }
+        }
+    }
+
+    /// Sets the variable font’s axes to those of one of the face’s named instances (such as
+    /// “Bold Condensed”), by index in the `fvar` table.
+    ///
+    /// Equivalent to `hb_font_set_var_named_instance`.
+    pub fn set_named_instance(&self, index: u32) {
+        unsafe {
+            hb_font_set_var_named_instance(self.ptr, index);
+        }
+    }
 }
 
-impl Font {
+impl<'a> Font<'a> {
+    /// Line metrics for horizontal text, if the font has them.
+    ///
+    /// Equivalent to `hb_font_get_h_extents`.
+    pub fn h_extents(&self) -> Option<FontExtents> {
+        unsafe {
+            let mut extents = hb_font_extents_t::default();
+            if hb_font_get_h_extents(self.ptr, &mut extents) != 0 {
+                Some(extents.into())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Line metrics for vertical text, if the font has them.
+    ///
+    /// Equivalent to `hb_font_get_v_extents`.
+    pub fn v_extents(&self) -> Option<FontExtents> {
+        unsafe {
+            let mut extents = hb_font_extents_t::default();
+            if hb_font_get_v_extents(self.ptr, &mut extents) != 0 {
+                Some(extents.into())
+            } else {
+                None
+            }
+        }
+    }
+
     // XXX: unicode and variation_selector are both Codepoint, *should* they both be char?
     pub fn get_glyph(&self, unicode: char, variation_selector: char) -> Option<Codepoint> {
         unsafe {
@@ -117,6 +205,64 @@ impl Font {
         }
     }
 
+    /// Looks up the nominal glyph for each character in `unicodes`, writing it to the same index
+    /// of `glyphs`, in a single call (rather than one `get_glyph` per character).
+    ///
+    /// Stops at the first character the font doesn’t cover, returning how many it did: all of
+    /// `unicodes` if that equals its length.
+    ///
+    /// # Panics
+    ///
+    /// If the slices differ in length.
+    ///
+    /// Equivalent to `hb_font_get_nominal_glyphs`.
+    pub fn nominal_glyphs(&self, unicodes: &[char], glyphs: &mut [Codepoint]) -> usize {
+        assert_eq!(unicodes.len(), glyphs.len(), "unicodes and glyphs differ in length");
+        unsafe {
+            hb_font_get_nominal_glyphs(self.ptr, unicodes.len() as libc::c_uint,
+                                       unicodes.as_ptr() as *const Codepoint,
+                                       mem::size_of::<char>() as libc::c_uint,
+                                       glyphs.as_mut_ptr(),
+                                       mem::size_of::<Codepoint>() as libc::c_uint) as usize
+        }
+    }
+
+    /// Gets the horizontal advance of each glyph in `glyphs`, writing it to the same index of
+    /// `advances`, in a single call.
+    ///
+    /// # Panics
+    ///
+    /// If the slices differ in length.
+    ///
+    /// Equivalent to `hb_font_get_glyph_h_advances`.
+    pub fn h_advances(&self, glyphs: &[Codepoint], advances: &mut [Position]) {
+        assert_eq!(glyphs.len(), advances.len(), "glyphs and advances differ in length");
+        unsafe {
+            hb_font_get_glyph_h_advances(self.ptr, glyphs.len() as libc::c_uint, glyphs.as_ptr(),
+                                         mem::size_of::<Codepoint>() as libc::c_uint,
+                                         advances.as_mut_ptr(),
+                                         mem::size_of::<Position>() as libc::c_uint)
+        }
+    }
+
+    /// Gets the vertical advance of each glyph in `glyphs`, writing it to the same index of
+    /// `advances`, in a single call.
+    ///
+    /// # Panics
+    ///
+    /// If the slices differ in length.
+    ///
+    /// Equivalent to `hb_font_get_glyph_v_advances`.
+    pub fn v_advances(&self, glyphs: &[Codepoint], advances: &mut [Position]) {
+        assert_eq!(glyphs.len(), advances.len(), "glyphs and advances differ in length");
+        unsafe {
+            hb_font_get_glyph_v_advances(self.ptr, glyphs.len() as libc::c_uint, glyphs.as_ptr(),
+                                         mem::size_of::<Codepoint>() as libc::c_uint,
+                                         advances.as_mut_ptr(),
+                                         mem::size_of::<Position>() as libc::c_uint)
+        }
+    }
+
     pub fn get_glyph_h_origin(&self, glyph: Codepoint) -> Option<(Position, Position)> {
         unsafe {
             let mut x = 0;
@@ -177,25 +323,46 @@ impl Font {
         }
     }
 
-    /*pub fn get_glyph_name(&self, glyph: Codepoint) -> Option<String> {
+    /// The glyph’s name, from the `post` or `CFF` table (or whatever the font funcs say), if it has
+    /// one.
+    ///
+    /// Equivalent to `hb_font_get_glyph_name`.
+    pub fn glyph_name(&self, glyph: Codepoint) -> Option<String> {
+        read_c_string(|name, size| unsafe {
+            hb_font_get_glyph_name(self.ptr, glyph, name, size) != 0
+        })
+    }
+
+    /// The glyph with the given name, if there is one.
+    ///
+    /// Equivalent to `hb_font_get_glyph_from_name`.
+    pub fn glyph_from_name(&self, name: &str) -> Option<Codepoint> {
         unsafe {
-            let size: u32 = …;
-            if hb_font_get_glyph_name(self.ptr, glyph, &mut name, size) {
-                Some(name)
+            let mut glyph = 0;
+            if hb_font_get_glyph_from_name(self.ptr, name.as_ptr() as *const libc::c_char,
+                                           name.len() as libc::c_int, &mut glyph) != 0 {
+                Some(glyph)
             } else {
                 None
             }
         }
     }
 
-    fn hb_font_get_glyph_name(font: *mut hb_font_t, glyph: Codepoint, name: *mut libc::c_char,
-                              size: libc::c_uint) -> hb_bool_t;
-    fn hb_font_get_glyph_from_name(font: *mut hb_font_t, name: *const libc::c_char,
-                                   len: libc::c_int, glyph: *mut Codepoint) -> hb_bool_t;
-    */
-
     // high-level funcs, with fallback
 
+    /// `h_extents` or `v_extents` according to the direction, falling back to made-up metrics
+    /// (ascender 0.8 em, descender −0.2 em; half an em either side for vertical) if the font
+    /// hasn’t any.
+    ///
+    /// Equivalent to `hb_font_get_extents_for_direction`.
+    pub fn extents_for_direction(&self, direction: Direction) -> FontExtents {
+        unsafe {
+            let mut extents = hb_font_extents_t::default();
+            hb_font_get_extents_for_direction(self.ptr, direction, &mut extents);
+            extents.into()
+        }
+    }
+
     pub fn get_glyph_advance_for_direction(&self, glyph: Codepoint, direction: Direction)
             -> (Position, Position) {
         unsafe {
@@ -270,13 +437,15 @@ impl Font {
         }
     }
 
-    /*/// Generates gidDDD if glyph has no name.
+    /// The glyph’s name, or `gidDDD` if it has none; the inverse of `glyph_from_string`.
+    ///
+    /// Equivalent to `hb_font_glyph_to_string`.
     pub fn glyph_to_string(&self, glyph: Codepoint) -> String {
-        let s: *mut libc::c_char;
-        let size: u32;
-        hb_font_glyph_to_string(self.ptr, glyph, s, size);
-        // ???
-    }*/
+        read_c_string(|s, size| {
+            unsafe { hb_font_glyph_to_string(self.ptr, glyph, s, size) };
+            true
+        }).unwrap_or_default()
+    }
 
     /// Parses gidDDD and uniUUUU strings automatically.
     pub fn glyph_from_string(&self, s: &str) -> Option<Codepoint> {
@@ -314,6 +483,570 @@ impl FontFuncs {
     }
 }
 
+impl Default for FontFuncs {
+    fn default() -> FontFuncs {
+        FontFuncs::new()
+    }
+}
+
+impl FontFuncs {
+    /// An immutable `FontFuncs` whose every func calls through to the `T` that `Font::set_funcs`
+    /// installs as the font data.
+    unsafe fn for_impl<T: FontFuncsImpl>() -> FontFuncs {
+        let mut ffuncs = FontFuncs::new();
+        let p = ffuncs.ptr;
+        let null = ptr::null_mut();
+        hb_font_funcs_set_font_h_extents_func(p, Some(font_h_extents_func::<T>), null, None);
+        hb_font_funcs_set_font_v_extents_func(p, Some(font_v_extents_func::<T>), null, None);
+        hb_font_funcs_set_nominal_glyph_func(p, Some(nominal_glyph_func::<T>), null, None);
+        hb_font_funcs_set_nominal_glyphs_func(p, Some(nominal_glyphs_func::<T>), null, None);
+        hb_font_funcs_set_variation_glyph_func(p, Some(variation_glyph_func::<T>), null, None);
+        hb_font_funcs_set_glyph_h_advance_func(p, Some(glyph_h_advance_func::<T>), null, None);
+        hb_font_funcs_set_glyph_v_advance_func(p, Some(glyph_v_advance_func::<T>), null, None);
+        hb_font_funcs_set_glyph_h_advances_func(p, Some(glyph_h_advances_func::<T>), null, None);
+        hb_font_funcs_set_glyph_v_advances_func(p, Some(glyph_v_advances_func::<T>), null, None);
+        hb_font_funcs_set_glyph_h_origin_func(p, Some(glyph_h_origin_func::<T>), null, None);
+        hb_font_funcs_set_glyph_v_origin_func(p, Some(glyph_v_origin_func::<T>), null, None);
+        hb_font_funcs_set_glyph_h_kerning_func(p, Some(glyph_h_kerning_func::<T>), null, None);
+        hb_font_funcs_set_glyph_v_kerning_func(p, Some(glyph_v_kerning_func::<T>), null, None);
+        hb_font_funcs_set_glyph_extents_func(p, Some(glyph_extents_func::<T>), null, None);
+        hb_font_funcs_set_glyph_contour_point_func(p, Some(glyph_contour_point_func::<T>), null,
+                                                   None);
+        hb_font_funcs_set_glyph_name_func(p, Some(glyph_name_func::<T>), null, None);
+        hb_font_funcs_set_glyph_from_name_func(p, Some(glyph_from_name_func::<T>), null, None);
+        ffuncs.make_immutable();
+        ffuncs
+    }
+}
+
+/// Glyph information from Rust, for a font engine of your own: implement this and install it on a
+/// font with `Font::set_funcs`.
+///
+/// Every method has a default which does what HarfBuzz does for a func that isn’t set: ask the
+/// parent font (see `Font::create_sub_font`), scaling what it says to this font’s scale. So on a
+/// sub-font, you need only override what you want to change; on a font with no parent, anything
+/// you don’t override comes out as nothing or zero.
+///
+/// Positions are in the font’s scale (`Font::scale`), which is the face’s units per em unless
+/// changed. HarfBuzz calls these from C, so they mustn’t panic.
+///
+/// The Rust side of `hb_font_funcs_t`.
+pub trait FontFuncsImpl {
+    /// Line metrics for horizontal text.
+    fn font_h_extents(&self, font: &Font<'_>) -> Option<FontExtents> {
+        font.parent().h_extents().map(|extents| {
+            let scale = |v| font.parent_scale((0, v)).1;
+            FontExtents {
+                ascender: scale(extents.ascender),
+                descender: scale(extents.descender),
+                line_gap: scale(extents.line_gap),
+            }
+        })
+    }
+
+    /// Line metrics for vertical text.
+    fn font_v_extents(&self, font: &Font<'_>) -> Option<FontExtents> {
+        font.parent().v_extents().map(|extents| {
+            let scale = |v| font.parent_scale((v, 0)).0;
+            FontExtents {
+                ascender: scale(extents.ascender),
+                descender: scale(extents.descender),
+                line_gap: scale(extents.line_gap),
+            }
+        })
+    }
+
+    /// The glyph for a character on its own, or `None` if the font doesn’t cover it.
+    fn nominal_glyph(&self, font: &Font<'_>, unicode: char) -> Option<Codepoint> {
+        font.parent().get_glyph(unicode, '\0')
+    }
+
+    /// `nominal_glyph` for many characters at once, for when shaping; `glyphs` is as long as
+    /// `unicodes`. Stops at the first character the font doesn’t cover, returning how many it did.
+    ///
+    /// The default calls `nominal_glyph` for each character; override it if you can do better.
+    fn nominal_glyphs(&self, font: &Font<'_>, unicodes: &[char], glyphs: &mut [Codepoint])
+            -> usize {
+        for (i, (&unicode, glyph)) in unicodes.iter().zip(glyphs.iter_mut()).enumerate() {
+            match self.nominal_glyph(font, unicode) {
+                Some(found) => *glyph = found,
+                None => return i,
+            }
+        }
+        unicodes.len()
+    }
+
+    /// The glyph for a character followed by a variation selector, or `None` if the font has no
+    /// such variant.
+    fn variation_glyph(&self, font: &Font<'_>, unicode: char, variation_selector: char)
+            -> Option<Codepoint> {
+        font.parent().get_glyph(unicode, variation_selector)
+    }
+
+    fn glyph_h_advance(&self, font: &Font<'_>, glyph: Codepoint) -> Position {
+        font.parent_scale((font.parent().get_glyph_h_advance(glyph), 0)).0
+    }
+
+    fn glyph_v_advance(&self, font: &Font<'_>, glyph: Codepoint) -> Position {
+        font.parent_scale((0, font.parent().get_glyph_v_advance(glyph))).1
+    }
+
+    /// `glyph_h_advance` for many glyphs at once, for when shaping; `advances` is as long as
+    /// `glyphs`.
+    ///
+    /// The default calls `glyph_h_advance` for each glyph; override it if you can do better.
+    fn glyph_h_advances(&self, font: &Font<'_>, glyphs: &[Codepoint], advances: &mut [Position]) {
+        for (&glyph, advance) in glyphs.iter().zip(advances.iter_mut()) {
+            *advance = self.glyph_h_advance(font, glyph);
+        }
+    }
+
+    /// `glyph_v_advance` for many glyphs at once; `advances` is as long as `glyphs`.
+    ///
+    /// The default calls `glyph_v_advance` for each glyph; override it if you can do better.
+    fn glyph_v_advances(&self, font: &Font<'_>, glyphs: &[Codepoint], advances: &mut [Position]) {
+        for (&glyph, advance) in glyphs.iter().zip(advances.iter_mut()) {
+            *advance = self.glyph_v_advance(font, glyph);
+        }
+    }
+
+    fn glyph_h_origin(&self, font: &Font<'_>, glyph: Codepoint) -> Option<(Position, Position)> {
+        font.parent().get_glyph_h_origin(glyph).map(|origin| font.parent_scale(origin))
+    }
+
+    fn glyph_v_origin(&self, font: &Font<'_>, glyph: Codepoint) -> Option<(Position, Position)> {
+        font.parent().get_glyph_v_origin(glyph).map(|origin| font.parent_scale(origin))
+    }
+
+    fn glyph_h_kerning(&self, font: &Font<'_>, left_glyph: Codepoint, right_glyph: Codepoint)
+            -> Position {
+        font.parent_scale((font.parent().get_glyph_h_kerning(left_glyph, right_glyph), 0)).0
+    }
+
+    fn glyph_v_kerning(&self, font: &Font<'_>, top_glyph: Codepoint, bottom_glyph: Codepoint)
+            -> Position {
+        font.parent_scale((0, font.parent().get_glyph_v_kerning(top_glyph, bottom_glyph))).1
+    }
+
+    fn glyph_extents(&self, font: &Font<'_>, glyph: Codepoint) -> Option<GlyphExtents> {
+        font.parent().get_glyph_extents(glyph).map(|extents| {
+            let (x_bearing, y_bearing) = font.parent_scale((extents.x_bearing, extents.y_bearing));
+            let (width, height) = font.parent_scale((extents.width, extents.height));
+            GlyphExtents { x_bearing, y_bearing, width, height }
+        })
+    }
+
+    fn glyph_contour_point(&self, font: &Font<'_>, glyph: Codepoint, point_index: u32)
+            -> Option<(Position, Position)> {
+        font.parent().get_glyph_contour_point(glyph, point_index)
+            .map(|point| font.parent_scale(point))
+    }
+
+    /// The glyph’s name, if it has one. HarfBuzz may truncate it to fit its buffer.
+    fn glyph_name(&self, font: &Font<'_>, glyph: Codepoint) -> Option<String> {
+        font.parent().glyph_name(glyph)
+    }
+
+    fn glyph_from_name(&self, font: &Font<'_>, name: &str) -> Option<Codepoint> {
+        font.parent().glyph_from_name(name)
+    }
+}
+
+impl<'a> Font<'a> {
+    /// Scales a pair of distances or a position from the parent font’s scale to this font’s, as
+    /// HarfBuzz’s `parent_scale_x_distance` and friends do.
+    fn parent_scale(&self, (x, y): (Position, Position)) -> (Position, Position) {
+        fn scale(v: Position, scale: i32, parent_scale: i32) -> Position {
+            if parent_scale == 0 || parent_scale == scale {
+                v
+            } else {
+                (v as i64 * scale as i64 / parent_scale as i64) as Position
+            }
+        }
+        let (x_scale, y_scale) = self.scale();
+        let (parent_x_scale, parent_y_scale) = self.parent().scale();
+        (scale(x, x_scale, parent_x_scale), scale(y, y_scale, parent_y_scale))
+    }
+}
+
+/// Gets a string from a HarfBuzz function that writes a NUL-terminated one into a buffer of the
+/// size it’s given, truncating if need be. Since there’s no telling whether it did, a string that
+/// fills the buffer is taken as a sign to try again with a bigger one.
+fn read_c_string<F>(mut f: F) -> Option<String>
+where F: FnMut(*mut libc::c_char, libc::c_uint) -> bool {
+    let mut buf = vec![0u8; 64];
+    loop {
+        buf[0] = 0;
+        if !f(buf.as_mut_ptr() as *mut libc::c_char, buf.len() as libc::c_uint) {
+            return None;
+        }
+        let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+        if len + 1 < buf.len() || buf.len() >= 1 << 16 {
+            return Some(String::from_utf8_lossy(&buf[..len]).into_owned());
+        }
+        buf.resize(buf.len() * 2, 0);
+    }
+}
+
+// The funcs `FontFuncs::for_impl` installs. HarfBuzz passes the `T` as `font_data`.
+
+/// Calls `f` with the `FontFuncsImpl` and a handle on the font, which HarfBuzz holds a reference to
+/// for the duration of the call (so the handle mustn’t release one).
+fn with_impl<T, R, F>(font: *mut hb_font_t, font_data: *mut libc::c_void, f: F) -> R
+where T: FontFuncsImpl, F: FnOnce(&T, &Font<'_>) -> R {
+    let funcs = unsafe { &*(font_data as *const T) };
+    let font = ManuallyDrop::new(Font::from(font));
+    f(funcs, &font)
+}
+
+fn store_pair(pair: Option<(Position, Position)>, x: *mut Position, y: *mut Position)
+        -> hb_bool_t {
+    match pair {
+        Some((x_value, y_value)) => {
+            unsafe {
+                *x = x_value;
+                *y = y_value;
+            }
+            1
+        },
+        None => 0,
+    }
+}
+
+fn store_font_extents(found: Option<FontExtents>, extents: *mut hb_font_extents_t) -> hb_bool_t {
+    match found {
+        Some(found) => {
+            unsafe { *extents = found.into() };
+            1
+        },
+        None => 0,
+    }
+}
+
+fn store_glyph(found: Option<Codepoint>, glyph: *mut Codepoint) -> hb_bool_t {
+    match found {
+        Some(found) => {
+            unsafe { *glyph = found };
+            1
+        },
+        None => 0,
+    }
+}
+
+extern "C" fn font_h_extents_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                    font_data: *mut libc::c_void,
+                                                    extents: *mut hb_font_extents_t,
+                                                    _user_data: *mut libc::c_void) -> hb_bool_t {
+    let found = with_impl::<T, _, _>(font, font_data, |funcs, font| funcs.font_h_extents(font));
+    store_font_extents(found, extents)
+}
+
+extern "C" fn font_v_extents_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                    font_data: *mut libc::c_void,
+                                                    extents: *mut hb_font_extents_t,
+                                                    _user_data: *mut libc::c_void) -> hb_bool_t {
+    let found = with_impl::<T, _, _>(font, font_data, |funcs, font| funcs.font_v_extents(font));
+    store_font_extents(found, extents)
+}
+
+extern "C" fn nominal_glyph_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                   font_data: *mut libc::c_void,
+                                                   unicode: Codepoint, glyph: *mut Codepoint,
+                                                   _user_data: *mut libc::c_void) -> hb_bool_t {
+    let found = char::from_u32(unicode).and_then(|unicode| {
+        with_impl::<T, _, _>(font, font_data, |funcs, font| funcs.nominal_glyph(font, unicode))
+    });
+    store_glyph(found, glyph)
+}
+
+/// The `i`th of the values `stride` bytes apart starting at `first`, as HarfBuzz passes arrays to
+/// the batch funcs (typically fields of the buffer’s glyph infos).
+unsafe fn strided<T>(first: *const T, stride: libc::c_uint, i: usize) -> *const T {
+    (first as *const u8).add(i * stride as usize) as *const T
+}
+
+thread_local! {
+    // The batch funcs’ strided arrays are copied through these, kept from one call to the next so
+    // that shaping doesn’t allocate for every batch.
+    static CHAR_SCRATCH: Cell<Vec<char>> = const { Cell::new(Vec::new()) };
+    static GLYPH_SCRATCH: Cell<Vec<Codepoint>> = const { Cell::new(Vec::new()) };
+    static POSITION_SCRATCH: Cell<Vec<Position>> = const { Cell::new(Vec::new()) };
+}
+
+/// Calls `f` with the emptied scratch buffer, taken out meanwhile so that a nested call (a parent
+/// font’s funcs being ours too) gets a new one rather than this one.
+fn with_scratch<T, R, F>(scratch: &'static LocalKey<Cell<Vec<T>>>, f: F) -> R
+where F: FnOnce(&mut Vec<T>) -> R {
+    let mut buffer = scratch.take();
+    buffer.clear();
+    let result = f(&mut buffer);
+    scratch.set(buffer);
+    result
+}
+
+extern "C" fn nominal_glyphs_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                    font_data: *mut libc::c_void,
+                                                    count: libc::c_uint,
+                                                    first_unicode: *const Codepoint,
+                                                    unicode_stride: libc::c_uint,
+                                                    first_glyph: *mut Codepoint,
+                                                    glyph_stride: libc::c_uint,
+                                                    _user_data: *mut libc::c_void)
+                                                 -> libc::c_uint {
+    with_scratch(&CHAR_SCRATCH, |unicodes| with_scratch(&GLYPH_SCRATCH, |glyphs| {
+        // Anything that isn’t a char isn’t covered, so we can stop there.
+        unicodes.extend((0..count as usize)
+            .map(|i| unsafe { *strided(first_unicode, unicode_stride, i) })
+            .map_while(char::from_u32));
+        glyphs.resize(unicodes.len(), 0);
+        let found = with_impl::<T, _, _>(font, font_data, |funcs, font| {
+            funcs.nominal_glyphs(font, unicodes, glyphs)
+        });
+        let found = cmp::min(found, glyphs.len());
+        for (i, &glyph) in glyphs[..found].iter().enumerate() {
+            unsafe { *(strided(first_glyph, glyph_stride, i) as *mut Codepoint) = glyph };
+        }
+        found as libc::c_uint
+    }))
+}
+
+extern "C" fn variation_glyph_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                     font_data: *mut libc::c_void,
+                                                     unicode: Codepoint,
+                                                     variation_selector: Codepoint,
+                                                     glyph: *mut Codepoint,
+                                                     _user_data: *mut libc::c_void)
+                                                  -> hb_bool_t {
+    let found = match (char::from_u32(unicode), char::from_u32(variation_selector)) {
+        (Some(unicode), Some(variation_selector)) => {
+            with_impl::<T, _, _>(font, font_data, |funcs, font| {
+                funcs.variation_glyph(font, unicode, variation_selector)
+            })
+        },
+        _ => None,
+    };
+    store_glyph(found, glyph)
+}
+
+extern "C" fn glyph_h_advance_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                     font_data: *mut libc::c_void,
+                                                     glyph: Codepoint,
+                                                     _user_data: *mut libc::c_void) -> Position {
+    with_impl::<T, _, _>(font, font_data, |funcs, font| funcs.glyph_h_advance(font, glyph))
+}
+
+extern "C" fn glyph_v_advance_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                     font_data: *mut libc::c_void,
+                                                     glyph: Codepoint,
+                                                     _user_data: *mut libc::c_void) -> Position {
+    with_impl::<T, _, _>(font, font_data, |funcs, font| funcs.glyph_v_advance(font, glyph))
+}
+
+fn glyph_advances<F>(count: libc::c_uint,
+                     first_glyph: *const Codepoint, glyph_stride: libc::c_uint,
+                     first_advance: *mut Position, advance_stride: libc::c_uint,
+                     f: F)
+where F: FnOnce(&[Codepoint], &mut [Position]) {
+    with_scratch(&GLYPH_SCRATCH, |glyphs| with_scratch(&POSITION_SCRATCH, |advances| {
+        glyphs.extend((0..count as usize)
+            .map(|i| unsafe { *strided(first_glyph, glyph_stride, i) }));
+        advances.resize(glyphs.len(), 0);
+        f(glyphs, advances);
+        for (i, &advance) in advances.iter().enumerate() {
+            unsafe { *(strided(first_advance, advance_stride, i) as *mut Position) = advance };
+        }
+    }))
+}
+
+extern "C" fn glyph_h_advances_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                      font_data: *mut libc::c_void,
+                                                      count: libc::c_uint,
+                                                      first_glyph: *const Codepoint,
+                                                      glyph_stride: libc::c_uint,
+                                                      first_advance: *mut Position,
+                                                      advance_stride: libc::c_uint,
+                                                      _user_data: *mut libc::c_void) {
+    glyph_advances(count, first_glyph, glyph_stride, first_advance, advance_stride,
+                   |glyphs, advances| {
+        with_impl::<T, _, _>(font, font_data, |funcs, font| {
+            funcs.glyph_h_advances(font, glyphs, advances)
+        })
+    })
+}
+
+extern "C" fn glyph_v_advances_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                      font_data: *mut libc::c_void,
+                                                      count: libc::c_uint,
+                                                      first_glyph: *const Codepoint,
+                                                      glyph_stride: libc::c_uint,
+                                                      first_advance: *mut Position,
+                                                      advance_stride: libc::c_uint,
+                                                      _user_data: *mut libc::c_void) {
+    glyph_advances(count, first_glyph, glyph_stride, first_advance, advance_stride,
+                   |glyphs, advances| {
+        with_impl::<T, _, _>(font, font_data, |funcs, font| {
+            funcs.glyph_v_advances(font, glyphs, advances)
+        })
+    })
+}
+
+extern "C" fn glyph_h_origin_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                    font_data: *mut libc::c_void,
+                                                    glyph: Codepoint,
+                                                    x: *mut Position, y: *mut Position,
+                                                    _user_data: *mut libc::c_void) -> hb_bool_t {
+    let origin = with_impl::<T, _, _>(font, font_data, |funcs, font| {
+        funcs.glyph_h_origin(font, glyph)
+    });
+    store_pair(origin, x, y)
+}
+
+extern "C" fn glyph_v_origin_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                    font_data: *mut libc::c_void,
+                                                    glyph: Codepoint,
+                                                    x: *mut Position, y: *mut Position,
+                                                    _user_data: *mut libc::c_void) -> hb_bool_t {
+    let origin = with_impl::<T, _, _>(font, font_data, |funcs, font| {
+        funcs.glyph_v_origin(font, glyph)
+    });
+    store_pair(origin, x, y)
+}
+
+extern "C" fn glyph_h_kerning_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                     font_data: *mut libc::c_void,
+                                                     left_glyph: Codepoint,
+                                                     right_glyph: Codepoint,
+                                                     _user_data: *mut libc::c_void) -> Position {
+    with_impl::<T, _, _>(font, font_data, |funcs, font| {
+        funcs.glyph_h_kerning(font, left_glyph, right_glyph)
+    })
+}
+
+extern "C" fn glyph_v_kerning_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                     font_data: *mut libc::c_void,
+                                                     top_glyph: Codepoint,
+                                                     bottom_glyph: Codepoint,
+                                                     _user_data: *mut libc::c_void) -> Position {
+    with_impl::<T, _, _>(font, font_data, |funcs, font| {
+        funcs.glyph_v_kerning(font, top_glyph, bottom_glyph)
+    })
+}
+
+extern "C" fn glyph_extents_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                   font_data: *mut libc::c_void,
+                                                   glyph: Codepoint,
+                                                   extents: *mut GlyphExtents,
+                                                   _user_data: *mut libc::c_void) -> hb_bool_t {
+    match with_impl::<T, _, _>(font, font_data, |funcs, font| funcs.glyph_extents(font, glyph)) {
+        Some(found) => {
+            unsafe { *extents = found };
+            1
+        },
+        None => 0,
+    }
+}
+
+extern "C" fn glyph_contour_point_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                         font_data: *mut libc::c_void,
+                                                         glyph: Codepoint,
+                                                         point_index: libc::c_uint,
+                                                         x: *mut Position, y: *mut Position,
+                                                         _user_data: *mut libc::c_void)
+                                                      -> hb_bool_t {
+    store_pair(with_impl::<T, _, _>(font, font_data, |funcs, font| {
+        funcs.glyph_contour_point(font, glyph, point_index)
+    }), x, y)
+}
+
+extern "C" fn glyph_name_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                font_data: *mut libc::c_void,
+                                                glyph: Codepoint,
+                                                name: *mut libc::c_char, size: libc::c_uint,
+                                                _user_data: *mut libc::c_void) -> hb_bool_t {
+    match with_impl::<T, _, _>(font, font_data, |funcs, font| funcs.glyph_name(font, glyph)) {
+        Some(glyph_name) => {
+            // Truncate to fit, leaving room for the NUL.
+            if size > 0 {
+                let len = cmp::min(glyph_name.len(), size as usize - 1);
+                unsafe {
+                    ptr::copy_nonoverlapping(glyph_name.as_ptr(), name as *mut u8, len);
+                    *name.add(len) = 0;
+                }
+            }
+            1
+        },
+        None => 0,
+    }
+}
+
+extern "C" fn glyph_from_name_func<T: FontFuncsImpl>(font: *mut hb_font_t,
+                                                     font_data: *mut libc::c_void,
+                                                     name: *const libc::c_char,
+                                                     len: libc::c_int,
+                                                     glyph: *mut Codepoint,
+                                                     _user_data: *mut libc::c_void)
+                                                  -> hb_bool_t {
+    // A negative length means the name is NUL-terminated.
+    let name = unsafe {
+        if len < 0 {
+            CStr::from_ptr(name).to_bytes()
+        } else {
+            slice::from_raw_parts(name as *const u8, len as usize)
+        }
+    };
+    let found = str::from_utf8(name).ok().and_then(|name| {
+        with_impl::<T, _, _>(font, font_data, |funcs, font| funcs.glyph_from_name(font, name))
+    });
+    store_glyph(found, glyph)
+}
+
+/// Line metrics: how far lines of text extend either side of the baseline, and the extra space
+/// wanted between them. For horizontal text these are vertical distances, and vice versa.
+///
+/// Note that descender is negative in coordinate systems that grow up.
+///
+/// Equivalent to `hb_font_extents_t`, without the reserved fields.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FontExtents {
+    /// the typographic ascender: how far lines extend above (or to the right of) the baseline.
+    pub ascender: Position,
+    /// the typographic descender: how far lines extend below (or to the left of) the baseline.
+    pub descender: Position,
+    /// suggested line spacing gap.
+    pub line_gap: Position,
+}
+
+/// The real `hb_font_extents_t`.
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+struct hb_font_extents_t {
+    ascender: Position,
+    descender: Position,
+    line_gap: Position,
+    reserved: [Position; 9],
+}
+
+impl From<hb_font_extents_t> for FontExtents {
+    fn from(extents: hb_font_extents_t) -> FontExtents {
+        FontExtents {
+            ascender: extents.ascender,
+            descender: extents.descender,
+            line_gap: extents.line_gap,
+        }
+    }
+}
+
+impl From<FontExtents> for hb_font_extents_t {
+    fn from(extents: FontExtents) -> hb_font_extents_t {
+        hb_font_extents_t {
+            ascender: extents.ascender,
+            descender: extents.descender,
+            line_gap: extents.line_gap,
+            reserved: [0; 9],
+        }
+    }
+}
+
 /// Note that height is negative in coordinate systems that grow up.
 ///
 /// Equivalent to `hb_glyph_extents_t`.
@@ -321,13 +1054,13 @@ impl FontFuncs {
 #[repr(C)]
 pub struct GlyphExtents {
     /// left side of glyph from origin.
-    x_bearing: Position,
+    pub x_bearing: Position,
     /// top side of glyph from origin.
-    y_bearing: Position,
+    pub y_bearing: Position,
     /// distance from left to right side.
-    width: Position,
+    pub width: Position,
     /// distance from top to bottom side.
-    height: Position,
+    pub height: Position,
 }
 
 // FFI:
@@ -337,10 +1070,26 @@ extern "C" {
 
     // func setters
 
-    fn hb_font_funcs_set_glyph_func(ffuncs: *mut hb_font_funcs_t,
-                                    func: hb_font_get_glyph_func_t,
-                                    user_data: *mut libc::c_void,
-                                    destroy: hb_destroy_func_t);
+    fn hb_font_funcs_set_font_h_extents_func(ffuncs: *mut hb_font_funcs_t,
+                                             func: hb_font_get_font_h_extents_func_t,
+                                             user_data: *mut libc::c_void,
+                                             destroy: hb_destroy_func_t);
+    fn hb_font_funcs_set_font_v_extents_func(ffuncs: *mut hb_font_funcs_t,
+                                             func: hb_font_get_font_v_extents_func_t,
+                                             user_data: *mut libc::c_void,
+                                             destroy: hb_destroy_func_t);
+    fn hb_font_funcs_set_nominal_glyph_func(ffuncs: *mut hb_font_funcs_t,
+                                            func: hb_font_get_nominal_glyph_func_t,
+                                            user_data: *mut libc::c_void,
+                                            destroy: hb_destroy_func_t);
+    fn hb_font_funcs_set_nominal_glyphs_func(ffuncs: *mut hb_font_funcs_t,
+                                             func: hb_font_get_nominal_glyphs_func_t,
+                                             user_data: *mut libc::c_void,
+                                             destroy: hb_destroy_func_t);
+    fn hb_font_funcs_set_variation_glyph_func(ffuncs: *mut hb_font_funcs_t,
+                                              func: hb_font_get_variation_glyph_func_t,
+                                              user_data: *mut libc::c_void,
+                                              destroy: hb_destroy_func_t);
     fn hb_font_funcs_set_glyph_h_advance_func(ffuncs: *mut hb_font_funcs_t,
                                               func: hb_font_get_glyph_h_advance_func_t,
                                               user_data: *mut libc::c_void,
@@ -349,6 +1098,14 @@ extern "C" {
                                               func: hb_font_get_glyph_v_advance_func_t,
                                               user_data: *mut libc::c_void,
                                               destroy: hb_destroy_func_t);
+    fn hb_font_funcs_set_glyph_h_advances_func(ffuncs: *mut hb_font_funcs_t,
+                                               func: hb_font_get_glyph_h_advances_func_t,
+                                               user_data: *mut libc::c_void,
+                                               destroy: hb_destroy_func_t);
+    fn hb_font_funcs_set_glyph_v_advances_func(ffuncs: *mut hb_font_funcs_t,
+                                               func: hb_font_get_glyph_v_advances_func_t,
+                                               user_data: *mut libc::c_void,
+                                               destroy: hb_destroy_func_t);
     fn hb_font_funcs_set_glyph_h_origin_func(ffuncs: *mut hb_font_funcs_t,
                                              func: hb_font_get_glyph_h_origin_func_t,
                                              user_data: *mut libc::c_void,
@@ -384,10 +1141,22 @@ extern "C" {
 
     // func dispatch
 
+    fn hb_font_get_h_extents(font: *mut hb_font_t, extents: *mut hb_font_extents_t) -> hb_bool_t;
+    fn hb_font_get_v_extents(font: *mut hb_font_t, extents: *mut hb_font_extents_t) -> hb_bool_t;
     fn hb_font_get_glyph(font: *mut hb_font_t, unicode: Codepoint,
                          variation_selector: Codepoint, glyph: *mut Codepoint) -> hb_bool_t;
+    fn hb_font_get_nominal_glyphs(font: *mut hb_font_t, count: libc::c_uint,
+                                  first_unicode: *const Codepoint, unicode_stride: libc::c_uint,
+                                  first_glyph: *mut Codepoint,
+                                  glyph_stride: libc::c_uint) -> libc::c_uint;
     fn hb_font_get_glyph_h_advance(font: *mut hb_font_t, glyph: Codepoint) -> Position;
     fn hb_font_get_glyph_v_advance(font: *mut hb_font_t, glyph: Codepoint) -> Position;
+    fn hb_font_get_glyph_h_advances(font: *mut hb_font_t, count: libc::c_uint,
+                                    first_glyph: *const Codepoint, glyph_stride: libc::c_uint,
+                                    first_advance: *mut Position, advance_stride: libc::c_uint);
+    fn hb_font_get_glyph_v_advances(font: *mut hb_font_t, count: libc::c_uint,
+                                    first_glyph: *const Codepoint, glyph_stride: libc::c_uint,
+                                    first_advance: *mut Position, advance_stride: libc::c_uint);
     fn hb_font_get_glyph_h_origin(font: *mut hb_font_t, glyph: Codepoint,
                                   x: *mut Position, y: *mut Position) -> hb_bool_t;
     fn hb_font_get_glyph_v_origin(font: *mut hb_font_t, glyph: Codepoint,
@@ -408,6 +1177,8 @@ extern "C" {
 
     // high-level funcs, with fallback
 
+    fn hb_font_get_extents_for_direction(font: *mut hb_font_t, direction: Direction,
+                                         extents: *mut hb_font_extents_t);
     fn hb_font_get_glyph_advance_for_direction(font: *mut hb_font_t,
                                                glyph: Codepoint, direction: Direction,
                                                x: *mut Position, y: *mut Position);
@@ -456,17 +1227,47 @@ extern "C" {
     fn hb_font_set_ppem(font: *mut hb_font_t, x_ppem: libc::c_uint, y_ppem: libc::c_uint);
     fn hb_font_get_ppem(font: *mut hb_font_t,
                         x_ppem: *mut libc::c_uint, y_ppem: *mut libc::c_uint);
+    fn hb_font_set_variations(font: *mut hb_font_t, variations: *const Variation,
+                              variations_length: libc::c_uint);
+    fn hb_font_set_var_coords_normalized(font: *mut hb_font_t, coords: *const libc::c_int,
+                                         coords_length: libc::c_uint);
+    fn hb_font_get_var_coords_normalized(font: *mut hb_font_t,
+                                         length: *mut libc::c_uint) -> *const libc::c_int;
+    fn hb_font_set_var_named_instance(font: *mut hb_font_t, instance_index: libc::c_uint);
 }
 
 // func types
 
-type hb_font_get_glyph_func_t = Option<extern "C" fn(font: *mut hb_font_t,
-                                                     font_data: *mut libc::c_void,
-                                                     unicode: Codepoint,
-                                                     variation_selector: Codepoint,
-                                                     glyph: *mut Codepoint,
-                                                     user_data: *mut libc::c_void)
-                                                  -> hb_bool_t>;
+type hb_font_get_font_extents_func_t = Option<extern "C" fn(font: *mut hb_font_t,
+                                                            font_data: *mut libc::c_void,
+                                                            extents: *mut hb_font_extents_t,
+                                                            user_data: *mut libc::c_void)
+                                                         -> hb_bool_t>;
+type hb_font_get_font_h_extents_func_t = hb_font_get_font_extents_func_t;
+type hb_font_get_font_v_extents_func_t = hb_font_get_font_extents_func_t;
+
+type hb_font_get_nominal_glyph_func_t = Option<extern "C" fn(font: *mut hb_font_t,
+                                                             font_data: *mut libc::c_void,
+                                                             unicode: Codepoint,
+                                                             glyph: *mut Codepoint,
+                                                             user_data: *mut libc::c_void)
+                                                          -> hb_bool_t>;
+type hb_font_get_nominal_glyphs_func_t = Option<extern "C" fn(font: *mut hb_font_t,
+                                                              font_data: *mut libc::c_void,
+                                                              count: libc::c_uint,
+                                                              first_unicode: *const Codepoint,
+                                                              unicode_stride: libc::c_uint,
+                                                              first_glyph: *mut Codepoint,
+                                                              glyph_stride: libc::c_uint,
+                                                              user_data: *mut libc::c_void)
+                                                           -> libc::c_uint>;
+type hb_font_get_variation_glyph_func_t = Option<extern "C" fn(font: *mut hb_font_t,
+                                                               font_data: *mut libc::c_void,
+                                                               unicode: Codepoint,
+                                                               variation_selector: Codepoint,
+                                                               glyph: *mut Codepoint,
+                                                               user_data: *mut libc::c_void)
+                                                            -> hb_bool_t>;
 
 type hb_font_get_glyph_advance_func_t = Option<extern "C" fn(font: *mut hb_font_t,
                                                              font_data: *mut libc::c_void,
@@ -476,6 +1277,17 @@ type hb_font_get_glyph_advance_func_t = Option<extern "C" fn(font: *mut hb_font_
 type hb_font_get_glyph_h_advance_func_t = hb_font_get_glyph_advance_func_t;
 type hb_font_get_glyph_v_advance_func_t = hb_font_get_glyph_advance_func_t;
 
+type hb_font_get_glyph_advances_func_t = Option<extern "C" fn(font: *mut hb_font_t,
+                                                              font_data: *mut libc::c_void,
+                                                              count: libc::c_uint,
+                                                              first_glyph: *const Codepoint,
+                                                              glyph_stride: libc::c_uint,
+                                                              first_advance: *mut Position,
+                                                              advance_stride: libc::c_uint,
+                                                              user_data: *mut libc::c_void)>;
+type hb_font_get_glyph_h_advances_func_t = hb_font_get_glyph_advances_func_t;
+type hb_font_get_glyph_v_advances_func_t = hb_font_get_glyph_advances_func_t;
+
 type hb_font_get_glyph_origin_func_t = Option<extern "C" fn(font: *mut hb_font_t,
                                                             font_data: *mut libc::c_void,
                                                             glyph: Codepoint,
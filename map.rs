@@ -0,0 +1,283 @@
+//! Derived from `hb-map.h`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::iter::FromIterator;
+
+use crate::common::{Codepoint, hb_bool_t};
+use crate::set::{Set, hb_set_t};
+
+define_boxed_type! {
+    /// A map from integers to integers (e.g. old glyph IDs to new ones), as HarfBuzz takes and
+    /// returns them.
+    ///
+    /// As with the other HarfBuzz types, cloning a map makes another handle on the same map, so
+    /// that changes through one show through the other; use `copy` for a separate one.
+    struct Map;
+    enum hb_map_t;
+    fn hb_map_get_empty;
+    fn hb_map_reference;
+    fn hb_map_destroy;
+    fn hb_map_set_user_data;
+    fn hb_map_get_user_data;
+    // Maps don’t have make_immutable and is_immutable.
+}
+
+impl Map {
+    /// Equivalent to `hb_map_create`.
+    pub fn new() -> Map {
+        unsafe {
+            Map::from(hb_map_create())
+        }
+    }
+
+    /// A new map with the same contents.
+    ///
+    /// Equivalent to `hb_map_copy`.
+    pub fn copy(&self) -> Map {
+        unsafe {
+            Map::from(hb_map_copy(self.ptr))
+        }
+    }
+
+    /// Whether every change to the map so far has been able to allocate what it needed. Once one
+    /// hasn’t, the map is empty and stays that way.
+    ///
+    /// Equivalent to `hb_map_allocation_successful`.
+    pub fn allocation_successful(&self) -> bool {
+        unsafe {
+            hb_map_allocation_successful(self.ptr) != 0
+        }
+    }
+
+    /// Equivalent to `hb_map_clear`.
+    pub fn clear(&mut self) {
+        unsafe {
+            hb_map_clear(self.ptr)
+        }
+    }
+
+    /// Equivalent to `hb_map_is_empty`.
+    pub fn is_empty(&self) -> bool {
+        unsafe {
+            hb_map_is_empty(self.ptr) != 0
+        }
+    }
+
+    /// How many keys are in the map.
+    ///
+    /// Equivalent to `hb_map_get_population`.
+    pub fn len(&self) -> u32 {
+        unsafe {
+            hb_map_get_population(self.ptr)
+        }
+    }
+
+    /// Equivalent to `hb_map_has`.
+    pub fn contains_key(&self, key: u32) -> bool {
+        unsafe {
+            hb_map_has(self.ptr, key) != 0
+        }
+    }
+
+    /// Equivalent to `hb_map_get`, which can’t tell a missing key from one mapped to `u32::MAX`.
+    pub fn get(&self, key: u32) -> Option<u32> {
+        if self.contains_key(key) {
+            Some(unsafe { hb_map_get(self.ptr, key) })
+        } else {
+            None
+        }
+    }
+
+    /// Maps `key` to `value`, returning what it was mapped to before, if anything.
+    ///
+    /// Equivalent to `hb_map_set`.
+    pub fn insert(&mut self, key: u32, value: u32) -> Option<u32> {
+        let old = self.get(key);
+        unsafe {
+            hb_map_set(self.ptr, key, value);
+        }
+        old
+    }
+
+    /// Removes `key` from the map, returning what it was mapped to, if it was there.
+    ///
+    /// Equivalent to `hb_map_del`.
+    pub fn remove(&mut self, key: u32) -> Option<u32> {
+        let old = self.get(key);
+        unsafe {
+            hb_map_del(self.ptr, key);
+        }
+        old
+    }
+
+    /// Inserts everything in `other`, replacing what its keys were mapped to here.
+    ///
+    /// Equivalent to `hb_map_update`.
+    pub fn update(&mut self, other: &Map) {
+        unsafe {
+            hb_map_update(self.ptr, other.ptr)
+        }
+    }
+
+    /// The key-value pairs in the map, in no particular order.
+    ///
+    /// Equivalent to `hb_map_next`.
+    pub fn iter(&self) -> MapIter<'_> {
+        MapIter { map: self, index: Some(-1) }
+    }
+
+    /// The map’s keys, except for `u32::MAX`, which can’t be in a set.
+    ///
+    /// Equivalent to `hb_map_keys`.
+    pub fn keys(&self) -> Set {
+        let keys = Set::new();
+        unsafe {
+            hb_map_keys(self.ptr, keys.ptr);
+        }
+        keys
+    }
+
+    /// The values the map’s keys are mapped to, each once, except for `u32::MAX`.
+    ///
+    /// Equivalent to `hb_map_values`.
+    pub fn values(&self) -> Set {
+        let values = Set::new();
+        unsafe {
+            hb_map_values(self.ptr, values.ptr);
+        }
+        values
+    }
+}
+
+impl Default for Map {
+    fn default() -> Map {
+        Map::new()
+    }
+}
+
+/// Equivalent to `hb_map_is_equal`.
+impl PartialEq for Map {
+    fn eq(&self, other: &Map) -> bool {
+        unsafe {
+            hb_map_is_equal(self.ptr, other.ptr) != 0
+        }
+    }
+}
+
+impl Eq for Map {}
+
+/// Equivalent to `hb_map_hash`.
+impl Hash for Map {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        unsafe {
+            hb_map_hash(self.ptr).hash(state)
+        }
+    }
+}
+
+impl fmt::Debug for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<(u32, u32)> for Map {
+    fn from_iter<I: IntoIterator<Item = (u32, u32)>>(iter: I) -> Map {
+        let mut map = Map::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl Extend<(u32, u32)> for Map {
+    fn extend<I: IntoIterator<Item = (u32, u32)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            unsafe {
+                hb_map_set(self.ptr, key, value);
+            }
+        }
+    }
+}
+
+impl<'m> IntoIterator for &'m Map {
+    type Item = (u32, u32);
+    type IntoIter = MapIter<'m>;
+
+    fn into_iter(self) -> MapIter<'m> {
+        self.iter()
+    }
+}
+
+impl<S> From<HashMap<u32, u32, S>> for Map {
+    fn from(map: HashMap<u32, u32, S>) -> Map {
+        map.into_iter().collect()
+    }
+}
+
+impl<'m, S> From<&'m HashMap<u32, u32, S>> for Map {
+    fn from(map: &'m HashMap<u32, u32, S>) -> Map {
+        map.iter().map(|(&key, &value)| (key, value)).collect()
+    }
+}
+
+impl<'m, S: BuildHasher + Default> From<&'m Map> for HashMap<u32, u32, S> {
+    fn from(map: &'m Map) -> HashMap<u32, u32, S> {
+        map.iter().collect()
+    }
+}
+
+impl<S: BuildHasher + Default> From<Map> for HashMap<u32, u32, S> {
+    fn from(map: Map) -> HashMap<u32, u32, S> {
+        HashMap::from(&map)
+    }
+}
+
+/// The key-value pairs in a map: see `Map::iter`.
+pub struct MapIter<'m> {
+    map: &'m Map,
+    /// Where HarfBuzz is up to, `None` once there are no more.
+    index: Option<libc::c_int>,
+}
+
+impl<'m> Iterator for MapIter<'m> {
+    type Item = (u32, u32);
+
+    fn next(&mut self) -> Option<(u32, u32)> {
+        // HarfBuzz starts over after the end, so we stop there.
+        let mut index = self.index?;
+        let (mut key, mut value) = (0, 0);
+        unsafe {
+            if hb_map_next(self.map.ptr, &mut index, &mut key, &mut value) != 0 {
+                self.index = Some(index);
+                Some((key, value))
+            } else {
+                self.index = None;
+                None
+            }
+        }
+    }
+}
+
+// FFI:
+
+extern "C" {
+    fn hb_map_create() -> *mut hb_map_t;
+    fn hb_map_copy(map: *mut hb_map_t) -> *mut hb_map_t;
+    fn hb_map_allocation_successful(map: *mut hb_map_t) -> hb_bool_t;
+    fn hb_map_clear(map: *mut hb_map_t);
+    fn hb_map_is_empty(map: *mut hb_map_t) -> hb_bool_t;
+    fn hb_map_get_population(map: *mut hb_map_t) -> libc::c_uint;
+    fn hb_map_is_equal(map: *mut hb_map_t, other: *mut hb_map_t) -> hb_bool_t;
+    fn hb_map_hash(map: *mut hb_map_t) -> libc::c_uint;
+    fn hb_map_set(map: *mut hb_map_t, key: Codepoint, value: Codepoint);
+    fn hb_map_get(map: *mut hb_map_t, key: Codepoint) -> Codepoint;
+    fn hb_map_del(map: *mut hb_map_t, key: Codepoint);
+    fn hb_map_has(map: *mut hb_map_t, key: Codepoint) -> hb_bool_t;
+    fn hb_map_update(map: *mut hb_map_t, other: *mut hb_map_t);
+    fn hb_map_next(map: *mut hb_map_t, idx: *mut libc::c_int, key: *mut Codepoint,
+                   value: *mut Codepoint) -> hb_bool_t;
+    fn hb_map_keys(map: *mut hb_map_t, keys: *mut hb_set_t);
+    fn hb_map_values(map: *mut hb_map_t, values: *mut hb_set_t);
+}
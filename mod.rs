@@ -1,33 +1,46 @@
+//! An idiomatic Rust port of the HarfBuzz API.
+//!
+//! This links against HarfBuzz found with pkg-config, or, with the `vendored` feature, against
+//! the copy in `harfbuzz/` which is compiled as part of the build.
+
 #![allow(non_camel_case_types)]
+// Plenty of the FFI declarations don’t have safe wrappers yet.
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate bitflags;
 
 #[macro_use]
 mod common;
 mod blob;
 mod buffer;
+mod draw;
 mod face;
 mod font;
+mod map;
+mod paint;
+mod raster;
+mod set;
 mod shape;
+mod svg;
 mod unicode;
 
-pub use self::common::{Language, Tag, Direction, Script};
-pub use self::blob::{Blob, MemoryMode};
+/// OpenType-specific functionality, derived from `hb-ot.h` and `hb-ot-*.h`.
+pub mod ot;
+
+pub use self::common::{Language, Tag, Direction, Script, Variation, Color};
+pub use self::blob::{Blob, WritableBlob, MemoryMode};
 pub use self::buffer::{Buffer, GlyphInfo, GlyphPosition, SegmentProperties, BufferContentType,
                        BufferFlags, BufferSerializeFlags,
                        BufferSerializeFormat, BufferClusterLevel,
-
-                       BUFFER_REPLACEMENT_CODEPOINT_DEFAULT,
-
-                       BUFFER_FLAGS_BOT,
-                       BUFFER_FLAGS_DEFAULT,
-                       BUFFER_FLAGS_EOT,
-                       BUFFER_FLAGS_PRESERVE_DEFAULT_IGNORABLES,
-
-                       BUFFER_SERIALIZE_FLAG_DEFAULT,
-                       BUFFER_SERIALIZE_FLAG_GLYPH_EXTENTS,
-                       BUFFER_SERIALIZE_FLAG_NO_CLUSTERS,
-                       BUFFER_SERIALIZE_FLAG_NO_GLYPH_NAMES,
-                       BUFFER_SERIALIZE_FLAG_NO_POSITIONS};
-pub use self::face::Face;
-pub use self::font::{Font, FontFuncs, GlyphExtents};
+                       BUFFER_REPLACEMENT_CODEPOINT_DEFAULT};
+pub use self::draw::{DrawSink, PathCommand};
+pub use self::face::{Face, FaceBuilder, Faces};
+pub use self::font::{Font, FontFuncs, FontFuncsImpl, FontExtents, GlyphExtents};
+pub use self::map::{Map, MapIter};
+pub use self::paint::{PaintSink, ColorLine, ColorStop, PaintExtend, CompositeMode};
+pub use self::raster::{Bitmap, rasterize_buffer};
+pub use self::set::{Set, SetIter, SetIntoIter, SetRanges};
 pub use self::shape::{Feature, shape, shape_full, ShaperList, list_shapers, Shapers};
+pub use self::svg::{SvgOptions, render_svg};
 pub use self::unicode::{UnicodeGeneralCategory, UnicodeCombiningClass, UnicodeFuncs};
@@ -1,52 +1,20 @@
 //! Derived from `hb-ot.h` and `hb-ot-*.h`.
 
-use harfbuzz::Tag;
-use harfbuzz::face::hb_face_t;
-use harfbuzz::font::hb_font_t;
+use std::ptr;
+use std::vec;
 
-extern "C" {
-    fn hb_ot_font_set_funcs(font: *mut hb_font_t);
-
-    fn hb_ot_layout_has_glyph_classes(face: *mut hb_face_t) -> hb_bool_t;
-
-    fn hb_ot_layout_get_glyph_class(face: *mut hb_face_t,
-                                    glyph: hb_codepoint_t) -> hb_ot_layout_glyph_class_t
+use crate::common::{Codepoint, Direction, Position, Tag, TAG_NONE, hb_bool_t, collect_array};
+use crate::face::{Face, hb_face_t};
+use crate::font::{Font, hb_font_t};
+use crate::set::{Set, hb_set_t};
 
-    fn hb_ot_layout_get_glyphs_in_class(face: *mut hb_face_t,
-                                        klass: hb_ot_layout_glyph_class_t,
-                                        glyphs: *mut hb_set_t /* out */);
-
-
-    fn hb_ot_layout_get_attach_points(face: *mut hb_face_t,
-                                      glyph: hb_codepoint_t,
-                                      start_offset: libc::c_uint,
-                                      point_count: *mut libc::c_uint /* in/out */,
-                                      point_array: *mut libc::c_uint /* out */) -> libc::c_uint;
-
-    fn hb_ot_layout_get_ligature_carets(font: *mut hb_font_t,
-                                        direction: hb_direction_t,
-                                        glyph: hb_codepoint_t,
-                                        start_offset: libc::c_uint,
-                                        caret_count: *mut libc::c_uint /* IN/OUT */,
-                                        caret_array: *mut hb_position_t /* OUT */) -> libc::c_uint;
-
-    fn hb_ot_layout_table_get_script_tags(face: *mut hb_face_t,
-                                          table_tag: hb_tag_t,
-                                          start_offset: libc::c_uint,
-                                          script_count: *mut libc::c_uint /* IN/OUT */,
-                                          script_tags: *mut hb_tag_t /* OUT */) -> libc::c_uint;
-    
-    fn hb_ot_layout_table_find_script(face: *mut hb_face_t,
-                                      table_tag: hb_tag_t,
-                                      script_tag: hb_tag_t,
-                                      script_index: *mut libc::c_uint) -> hb_bool_t;
-
-}
+pub mod color;
+pub mod var;
 
 // Derived from hb-ot-font.h
 
 #[inline]
-pub fn font_set_funcs(font: &mut Font) {
+pub fn font_set_funcs(font: &mut Font<'_>) {
     unsafe {
         hb_ot_font_set_funcs(font.ptr)
     }
@@ -55,50 +23,96 @@ pub fn font_set_funcs(font: &mut Font) {
 // Derived from hb-ot-layout.h
 
 /// Equivalent to `HB_OT_TAG_GDEF`.
-pub const TAG_GDEF: Tag = hb_tag!('G', 'D', 'E', 'F');
+pub const TAG_GDEF: Tag = hb_tag!(b'G', b'D', b'E', b'F');
 
-/// Equivalent to `HB_OT_TAG_GDEF`.
-pub const TAG_GSUB: Tag = hb_tag!('G', 'S', 'U', 'B');
+/// Equivalent to `HB_OT_TAG_GSUB`.
+pub const TAG_GSUB: Tag = hb_tag!(b'G', b'S', b'U', b'B');
 
-/// Equivalent to `HB_OT_TAG_GDEF`.
-pub const TAG_GPOS: Tag = hb_tag!('G', 'P', 'O', 'S');
+/// Equivalent to `HB_OT_TAG_GPOS`.
+pub const TAG_GPOS: Tag = hb_tag!(b'G', b'P', b'O', b'S');
 
-/// Equivalent to `HB_OT_TAG_GDEF`.
-pub const TAG_JSTF: Tag = hb_tag!('J', 'S', 'T', 'F');
+/// Equivalent to `HB_OT_TAG_JSTF`.
+pub const TAG_JSTF: Tag = hb_tag!(b'J', b'S', b'T', b'F');
 
 
 // GDEF
 
 /// Equivalent to `hb_ot_layout_has_glyph_classes`.
 #[inline]
-pub fn has_glyph_classes(face: &Face) -> bool {
+pub fn has_glyph_classes(face: &Face<'_>) -> bool {
     unsafe {
         hb_ot_layout_has_glyph_classes(face.ptr) != 0
     }
 }
 
 /// Equivalent to `hb_ot_layout_glyph_class_t`.
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 #[repr(C)]
 pub enum GlyphClass {
-  /// Equivalent to `HB_OT_LAYOUT_GLYPH_CLASS_UNCLASSIFIED`.
-  Unclassified = 0,
-  /// Equivalent to `HB_OT_LAYOUT_GLYPH_CLASS_BASE_GLYPH`
-  BaseGlyph = 1,
-  /// Equivalent to `HB_OT_LAYOUT_GLYPH_CLASS_LIGATURE`
-  Ligature = 2,
-  /// Equivalent to `HB_OT_LAYOUT_GLYPH_CLASS_MARK`
-  Mark = 3,
-  /// Equivalent to `HB_OT_LAYOUT_GLYPH_CLASS_COMPONENT`
-  Component = 4,
+    /// Equivalent to `HB_OT_LAYOUT_GLYPH_CLASS_UNCLASSIFIED`.
+    #[default]
+    Unclassified = 0,
+    /// Equivalent to `HB_OT_LAYOUT_GLYPH_CLASS_BASE_GLYPH`
+    BaseGlyph = 1,
+    /// Equivalent to `HB_OT_LAYOUT_GLYPH_CLASS_LIGATURE`
+    Ligature = 2,
+    /// Equivalent to `HB_OT_LAYOUT_GLYPH_CLASS_MARK`
+    Mark = 3,
+    /// Equivalent to `HB_OT_LAYOUT_GLYPH_CLASS_COMPONENT`
+    Component = 4,
 }
 
-pub fn get_glyph_class(face: &Face, glyph: hb_codepoint_t) -> GlyphClass { TODO }
-pub fn get_glyphs_in_class(face: &Face, class: GlyphClass) -> Set { TODO }
-/// Not that useful.  Provides list of attach points for a glyph that a client may want to cache.
-pub fn get_attach_points(face: &Face, glyph: hb_codepoint_t, start_offset: u32, ???) -> ??? { TODO }
-/// Ligature caret positions
-pub fn get_ligature_carets(face: &Face, direction: Direction, glyph: hb_codepoint_t, start_offset: u32, ???) -> ??? { TODO }
+/// The glyph’s class in the `GDEF` table; `Unclassified` if it hasn’t one, or there’s no table.
+///
+/// Equivalent to `hb_ot_layout_get_glyph_class`.
+pub fn get_glyph_class(face: &Face<'_>, glyph: Codepoint) -> GlyphClass {
+    match unsafe { hb_ot_layout_get_glyph_class(face.ptr, glyph) } {
+        1 => GlyphClass::BaseGlyph,
+        2 => GlyphClass::Ligature,
+        3 => GlyphClass::Mark,
+        4 => GlyphClass::Component,
+        // The raw class from the font, which may be anything.
+        _ => GlyphClass::Unclassified,
+    }
+}
+
+/// Every glyph the `GDEF` table puts in the class.
+///
+/// Equivalent to `hb_ot_layout_get_glyphs_in_class`.
+pub fn get_glyphs_in_class(face: &Face<'_>, class: GlyphClass) -> Set {
+    let glyphs = Set::new();
+    unsafe {
+        hb_ot_layout_get_glyphs_in_class(face.ptr, class as libc::c_uint, glyphs.ptr);
+    }
+    glyphs
+}
+
+/// The glyph’s attachment points, as indexes of its outline’s points. Not that useful: HarfBuzz
+/// doesn’t use them, but a client may want to cache them.
+///
+/// Equivalent to `hb_ot_layout_get_attach_points`.
+pub fn get_attach_points(face: &Face<'_>, glyph: Codepoint) -> Vec<u32> {
+    unsafe {
+        collect_array(|start_offset, point_count, point_array| {
+            hb_ot_layout_get_attach_points(face.ptr, glyph, start_offset, point_count, point_array)
+        })
+    }
+}
+
+/// Where the caret can go within a ligature glyph, between the characters it was made of: along
+/// the text’s direction from the glyph’s origin, in the font’s scale. A ligature of n characters
+/// has n − 1 carets, e.g. two for “ffi”; none if the font doesn’t say.
+///
+/// Equivalent to `hb_ot_layout_get_ligature_carets`.
+pub fn get_ligature_carets(font: &Font<'_>, direction: Direction,
+                           glyph: Codepoint) -> Vec<Position> {
+    unsafe {
+        collect_array(|start_offset, caret_count, caret_array| {
+            hb_ot_layout_get_ligature_carets(font.ptr, direction, glyph, start_offset, caret_count,
+                                             caret_array)
+        })
+    }
+}
 
 // GSUB/GPOS feature query and enumeration interface
 
@@ -109,176 +123,556 @@ const NO_FEATURE_INDEX: u32 = 0xFFFF;
 /// Equivalent to `HB_OT_LAYOUT_DEFAULT_LANGUAGE_INDEX`.
 const DEFAULT_LANGUAGE_INDEX: u32 = 0xFFFF;
 
-pub fn table_get_script_tags(face: &Face, table_tag: Tag, start_offset: u32, ???) -> ??? { TODO }
-pub fn table_find_script(face: &Face, table_tag: Tag, script_tag: Tag) -> ??? { TODO }
+impl<'a> Face<'a> {
+    /// The face’s `GSUB` or `GPOS` table (`TAG_GSUB`, `TAG_GPOS`), to find out which scripts,
+    /// languages and features it covers. A face without the table has no scripts or features.
+    ///
+    /// There is no equivalent in HarfBuzz, whose functions take the face and table tag each time.
+    pub fn layout(&self, table_tag: Tag) -> Layout<'a> {
+        Layout { face: self.clone(), table_tag }
+    }
+}
 
-// Derived from hb-ot-tag.h
+/// A face’s `GSUB` or `GPOS` table: see `Face::layout`.
+///
+/// There is no equivalent in HarfBuzz.
+#[derive(Clone)]
+pub struct Layout<'a> {
+    face: Face<'a>,
+    table_tag: Tag,
+}
 
-// Derived from hb-ot-shape.h
+impl<'a> Layout<'a> {
+    pub fn table_tag(&self) -> Tag {
+        self.table_tag
+    }
 
-// XXX XXX XXX XXX XXX ↓ scratch area ↓ XXX XXX XXX XXX XXX
+    /// The scripts the table has features for, in the order of its script list (i.e. by tag).
+    ///
+    /// Equivalent to `hb_ot_layout_table_get_script_tags`.
+    pub fn scripts(&self) -> vec::IntoIter<LayoutScript<'a>> {
+        let tags = unsafe {
+            collect_array(|start_offset, script_count, script_tags| {
+                hb_ot_layout_table_get_script_tags(self.face.ptr, self.table_tag, start_offset,
+                                                   script_count, script_tags)
+            })
+        };
+        tags.into_iter().enumerate().map(|(index, tag)| {
+            LayoutScript { layout: self.clone(), index: index as u32, tag }
+        }).collect::<Vec<_>>().into_iter()
+    }
 
+    /// The script with the given OpenType script tag (e.g. `latn`, not the ISO 15924 `Latn`), if
+    /// the table has it.
+    ///
+    /// Equivalent to `hb_ot_layout_table_find_script`.
+    pub fn find_script(&self, script_tag: Tag) -> Option<LayoutScript<'a>> {
+        let mut index = NO_SCRIPT_INDEX;
+        unsafe {
+            if hb_ot_layout_table_find_script(self.face.ptr, self.table_tag, script_tag,
+                                              &mut index) != 0 {
+                Some(LayoutScript { layout: self.clone(), index, tag: script_tag })
+            } else {
+                None
+            }
+        }
+    }
 
-/// Like find_script, but takes zero-terminated array of scripts to test
-fn hb_ot_layout_table_choose_script(face: *mut hb_face_t,
-                                    table_tag: hb_tag_t,
-                                    script_tags: *const hb_tag_t,
-                                    script_index: *mut libc::c_uint,
-                                    chosen_script: *mut hb_tag_t) -> hb_bool_t;
+    /// The tags of every feature in the table, in the order of its feature list, whatever script
+    /// and language they’re for; a tag appears once for each of its entries in the list.
+    ///
+    /// Equivalent to `hb_ot_layout_table_get_feature_tags`.
+    pub fn feature_tags(&self) -> Vec<Tag> {
+        unsafe {
+            collect_array(|start_offset, feature_count, feature_tags| {
+                hb_ot_layout_table_get_feature_tags(self.face.ptr, self.table_tag, start_offset,
+                                                    feature_count, feature_tags)
+            })
+        }
+    }
 
-fn hb_ot_layout_table_get_feature_tags(face: *mut hb_face_t,
-                                       table_tag: hb_tag_t,
-                                       start_offset: libc::c_uint,
-                                       feature_count: *mut libc::c_uint /* IN/OUT */,
-                                       feature_tags: *mut hb_tag_t /* OUT */) -> libc::c_uint;
+    /// How many lookups the table has; lookup indexes go from 0 to one less than this.
+    ///
+    /// Equivalent to `hb_ot_layout_table_get_lookup_count`.
+    pub fn lookup_count(&self) -> u32 {
+        unsafe {
+            hb_ot_layout_table_get_lookup_count(self.face.ptr, self.table_tag)
+        }
+    }
 
-fn hb_ot_layout_script_get_language_tags(face: *mut hb_face_t,
-                                         table_tag: hb_tag_t,
-                                         script_index: libc::c_uint,
-                                         start_offset: libc::c_uint,
-                                         language_count: *mut libc::c_uint /* IN/OUT */,
-                                         language_tags: *mut hb_tag_t /* OUT */) -> libc::c_uint;
-
-fn hb_ot_layout_script_find_language(face: *mut hb_face_t,
-                                     table_tag: hb_tag_t,
-                                     script_index: libc::c_uint,
-                                     language_tag: hb_tag_t,
-                                     language_index: *mut libc::c_uint) -> hb_bool_t;
-
-fn hb_ot_layout_language_get_required_feature_index(face: *mut hb_face_t,
-                                                    table_tag: hb_tag_t,
-                                                    script_index: libc::c_uint,
-                                                    language_index: libc::c_uint,
-                                                    feature_index: *mut libc::c_uint) -> hb_bool_t;
-
-fn hb_ot_layout_language_get_required_feature(face: *mut hb_face_t,
-                                              table_tag: hb_tag_t,
-                                              script_index: libc::c_uint,
-                                              language_index: libc::c_uint,
-                                              feature_index: *mut libc::c_uint,
-                                              feature_tag: *mut hb_tag_t) -> hb_bool_t;
+    /// The indexes of the lookups a feature applies, in the order it lists them. `feature_index`
+    /// is `LayoutFeature::index`, or a place in `feature_tags`.
+    ///
+    /// Equivalent to `hb_ot_layout_feature_get_lookups`.
+    pub fn feature_lookups(&self, feature_index: u32) -> vec::IntoIter<u32> {
+        unsafe {
+            collect_array(|start_offset, lookup_count, lookup_indexes| {
+                hb_ot_layout_feature_get_lookups(self.face.ptr, self.table_tag, feature_index,
+                                                 start_offset, lookup_count, lookup_indexes)
+            })
+        }.into_iter()
+    }
 
-fn hb_ot_layout_language_get_feature_indexes(face: *mut hb_face_t,
-                                             table_tag: hb_tag_t,
-                                             script_index: libc::c_uint,
-                                             language_index: libc::c_uint,
-                                             start_offset: libc::c_uint,
-                                             feature_count: *mut libc::c_uint /* IN/OUT */,
-                                             feature_indexes: *mut libc::c_uint /* OUT */) -> libc::c_uint;
+    /// The indexes of the lookups that the given features apply for the given scripts and
+    /// languages, by tag; `None` for any of them means all of them. The scripts’ default languages
+    /// are only included when `languages` is `None`.
+    ///
+    /// Equivalent to `hb_ot_layout_collect_lookups`.
+    pub fn collect_lookups(&self, scripts: Option<&[Tag]>, languages: Option<&[Tag]>,
+                           features: Option<&[Tag]>) -> Set {
+        let scripts = scripts.map(tag_list);
+        let languages = languages.map(tag_list);
+        let features = features.map(tag_list);
+        let as_ptr = |tags: &Option<Vec<Tag>>| tags.as_ref().map_or(ptr::null(), |t| t.as_ptr());
+        let lookup_indexes = Set::new();
+        unsafe {
+            hb_ot_layout_collect_lookups(self.face.ptr, self.table_tag, as_ptr(&scripts),
+                                         as_ptr(&languages), as_ptr(&features),
+                                         lookup_indexes.ptr);
+        }
+        lookup_indexes
+    }
 
-fn hb_ot_layout_language_get_feature_tags(face: *mut hb_face_t,
-                                          table_tag: hb_tag_t,
-                                          script_index: libc::c_uint,
-                                          language_index: libc::c_uint,
-                                          start_offset: libc::c_uint,
-                                          feature_count: *mut libc::c_uint /* IN/OUT */,
-                                          feature_tags: *mut hb_tag_t /* OUT */) -> libc::c_uint;
-
-fn hb_ot_layout_language_find_feature(face: *mut hb_face_t,
-                                      table_tag: hb_tag_t,
-                                      script_index: libc::c_uint,
-                                      language_index: libc::c_uint,
-                                      feature_tag: hb_tag_t,
-                                      feature_index: *mut libc::c_uint) -> hb_bool_t;
-
-fn hb_ot_layout_feature_get_lookups(face: *mut hb_face_t,
-                                    table_tag: hb_tag_t,
-                                    feature_index: libc::c_uint,
-                                    start_offset: libc::c_uint,
-                                    lookup_count: *mut libc::c_uint /* IN/OUT */,
-                                    lookup_indexes: *mut libc::c_uint /* OUT */) -> hb_bool_t;
-
-fn hb_ot_layout_table_get_lookup_count(face: *mut hb_face_t,
-                                       table_tag: hb_tag_t) -> libc::c_uint;
-
-
-fn hb_ot_layout_collect_lookups(face: *mut hb_face_t,
-			      table_tag: hb_tag_t,
-			      scripts: *const hb_tag_t,
-			      languages: *const hb_tag_t,
-			      features: *const hb_tag_t,
-			      lookup_indexes: *mut hb_set_t, /* OUT */);
-
-fn hb_ot_layout_lookup_collect_glyphs(face: *mut hb_face_t,
-				    table_tag: hb_tag_t,
-				    lookup_index: libc::c_uint,
-				    glyphs_before: *mut hb_set_t, /* OUT. May be NULL */
-				    glyphs_input: *mut hb_set_t,  /* OUT. May be NULL */
-				    glyphs_after: *mut hb_set_t,  /* OUT. May be NULL */
-				    glyphs_output: *mut hb_set_t  /* OUT. May be NULL */);
+    /// The glyphs a lookup could match or produce, so as to find which lookups touch a glyph.
+    ///
+    /// Equivalent to `hb_ot_layout_lookup_collect_glyphs`.
+    pub fn lookup_glyphs(&self, lookup_index: u32) -> LookupGlyphs {
+        let glyphs = LookupGlyphs {
+            before: Set::new(),
+            input: Set::new(),
+            after: Set::new(),
+            output: Set::new(),
+        };
+        unsafe {
+            hb_ot_layout_lookup_collect_glyphs(self.face.ptr, self.table_tag, lookup_index,
+                                               glyphs.before.ptr, glyphs.input.ptr,
+                                               glyphs.after.ptr, glyphs.output.ptr);
+        }
+        glyphs
+    }
+
+    /// Whether the lookup would substitute the glyph sequence, e.g. the components of a ligature;
+    /// if `zero_context`, only if it needs no context before or after the sequence to do so.
+    /// Always false for `GPOS`, which doesn’t substitute.
+    ///
+    /// Equivalent to `hb_ot_layout_lookup_would_substitute`.
+    pub fn lookup_would_substitute(&self, lookup_index: u32, glyphs: &[Codepoint],
+                                   zero_context: bool) -> bool {
+        if self.table_tag != TAG_GSUB {
+            return false;
+        }
+        unsafe {
+            hb_ot_layout_lookup_would_substitute(self.face.ptr, lookup_index, glyphs.as_ptr(),
+                                                 glyphs.len() as libc::c_uint,
+                                                 zero_context as hb_bool_t) != 0
+        }
+    }
+}
+
+/// The tags with `TAG_NONE` after them, as HarfBuzz takes lists of tags.
+fn tag_list(tags: &[Tag]) -> Vec<Tag> {
+    tags.iter().cloned().chain(Some(TAG_NONE)).collect()
+}
+
+/// The glyphs a lookup could match or produce: see `Layout::lookup_glyphs`.
+///
+/// There is no equivalent in HarfBuzz, which fills in the four sets passed to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LookupGlyphs {
+    /// Glyphs the lookup could need before the input, as context.
+    pub before: Set,
+    /// Glyphs the lookup could act on.
+    pub input: Set,
+    /// Glyphs the lookup could need after the input, as context.
+    pub after: Set,
+    /// Glyphs the lookup could substitute in; empty for `GPOS`.
+    pub output: Set,
+}
+
+/// A script in a `GSUB` or `GPOS` table: see `Layout::scripts`.
+///
+/// There is no equivalent in HarfBuzz, which identifies it by its index in the table.
+#[derive(Clone)]
+pub struct LayoutScript<'a> {
+    layout: Layout<'a>,
+    index: u32,
+    tag: Tag,
+}
+
+impl<'a> LayoutScript<'a> {
+    /// The script’s index in the table’s script list.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The OpenType script tag, e.g. `latn` or `DFLT`.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// The script’s default language system, for languages it has nothing specific for.
+    pub fn default_language(&self) -> LayoutLanguage<'a> {
+        LayoutLanguage { script: self.clone(), index: DEFAULT_LANGUAGE_INDEX, tag: None }
+    }
+
+    /// The languages the script has specific features for, by tag. This doesn’t include the
+    /// default language (`default_language`), which has no tag.
+    ///
+    /// Equivalent to `hb_ot_layout_script_get_language_tags`.
+    pub fn languages(&self) -> vec::IntoIter<LayoutLanguage<'a>> {
+        let layout = &self.layout;
+        let tags = unsafe {
+            collect_array(|start_offset, language_count, language_tags| {
+                hb_ot_layout_script_get_language_tags(layout.face.ptr, layout.table_tag,
+                                                      self.index, start_offset, language_count,
+                                                      language_tags)
+            })
+        };
+        tags.into_iter().enumerate().map(|(index, tag)| {
+            LayoutLanguage { script: self.clone(), index: index as u32, tag: Some(tag) }
+        }).collect::<Vec<_>>().into_iter()
+    }
+
+    /// The language with the given OpenType language tag (e.g. `TRK `), if the script has it.
+    ///
+    /// Equivalent to `hb_ot_layout_script_select_language` with one tag.
+    pub fn find_language(&self, language_tag: Tag) -> Option<LayoutLanguage<'a>> {
+        let mut index = DEFAULT_LANGUAGE_INDEX;
+        unsafe {
+            if hb_ot_layout_script_select_language(self.layout.face.ptr, self.layout.table_tag,
+                                                   self.index, 1, &language_tag,
+                                                   &mut index) != 0 {
+                Some(LayoutLanguage { script: self.clone(), index, tag: Some(language_tag) })
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// A language system of a script in a `GSUB` or `GPOS` table: see `LayoutScript::languages`.
+///
+/// There is no equivalent in HarfBuzz, which identifies it by its index in the script.
+#[derive(Clone)]
+pub struct LayoutLanguage<'a> {
+    script: LayoutScript<'a>,
+    index: u32,
+    /// `None` for the default language.
+    tag: Option<Tag>,
+}
+
+impl<'a> LayoutLanguage<'a> {
+    /// The language’s index in the script’s list of languages, or `None` for the default language.
+    pub fn index(&self) -> Option<u32> {
+        if self.index == DEFAULT_LANGUAGE_INDEX { None } else { Some(self.index) }
+    }
+
+    /// The OpenType language tag, e.g. `TRK `, or `None` for the default language.
+    pub fn tag(&self) -> Option<Tag> {
+        self.tag
+    }
+
+    pub fn script(&self) -> &LayoutScript<'a> {
+        &self.script
+    }
+
+    /// The feature the font says must always be applied for this language, if any. Few fonts
+    /// have one.
+    ///
+    /// Equivalent to `hb_ot_layout_language_get_required_feature`.
+    pub fn required_feature(&self) -> Option<LayoutFeature> {
+        let layout = &self.script.layout;
+        let mut index = NO_FEATURE_INDEX;
+        let mut tag = Tag::default();
+        unsafe {
+            if hb_ot_layout_language_get_required_feature(layout.face.ptr, layout.table_tag,
+                                                          self.script.index, self.index,
+                                                          &mut index, &mut tag) != 0 {
+                Some(LayoutFeature { index, tag, required: true })
+            } else {
+                None
+            }
+        }
+    }
+
+    /// The features for this language, the required feature (if any) first, and only there even
+    /// if the language lists it among the others too.
+    ///
+    /// Equivalent to `hb_ot_layout_language_get_required_feature`,
+    /// `hb_ot_layout_language_get_feature_indexes` and `hb_ot_layout_language_get_feature_tags`.
+    pub fn features(&self) -> vec::IntoIter<LayoutFeature> {
+        let layout = &self.script.layout;
+        let (indexes, tags) = unsafe {
+            (collect_array(|start_offset, feature_count, feature_indexes| {
+                hb_ot_layout_language_get_feature_indexes(layout.face.ptr, layout.table_tag,
+                                                          self.script.index, self.index,
+                                                          start_offset, feature_count,
+                                                          feature_indexes)
+            }),
+            collect_array(|start_offset, feature_count, feature_tags| {
+                hb_ot_layout_language_get_feature_tags(layout.face.ptr, layout.table_tag,
+                                                       self.script.index, self.index,
+                                                       start_offset, feature_count, feature_tags)
+            }))
+        };
+        let required = self.required_feature();
+        let required_index = required.map(|feature| feature.index);
+        let features = indexes.into_iter().zip(tags)
+            .filter(|&(index, _)| Some(index) != required_index)
+            .map(|(index, tag)| LayoutFeature { index, tag, required: false });
+        required.into_iter().chain(features).collect::<Vec<_>>().into_iter()
+    }
+
+    /// The feature with the given tag, if the language has it, whether required or not.
+    ///
+    /// Equivalent to `hb_ot_layout_language_get_required_feature` and
+    /// `hb_ot_layout_language_find_feature`.
+    pub fn find_feature(&self, feature_tag: Tag) -> Option<LayoutFeature> {
+        let required = self.required_feature().filter(|feature| feature.tag == feature_tag);
+        if required.is_some() {
+            return required;
+        }
+        let layout = &self.script.layout;
+        let mut index = NO_FEATURE_INDEX;
+        unsafe {
+            if hb_ot_layout_language_find_feature(layout.face.ptr, layout.table_tag,
+                                                  self.script.index, self.index, feature_tag,
+                                                  &mut index) != 0 {
+                Some(LayoutFeature { index, tag: feature_tag, required: false })
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// A feature of a language system in a `GSUB` or `GPOS` table: see `LayoutLanguage::features`.
+///
+/// There is no equivalent in HarfBuzz, which identifies it by its index in the table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LayoutFeature {
+    /// The feature’s index in the table’s feature list.
+    pub index: u32,
+    /// The feature tag, e.g. `liga`.
+    pub tag: Tag,
+    /// Whether it’s the language’s required feature, to be applied whatever the user asks for.
+    pub required: bool,
+}
+
+// Derived from hb-ot-tag.h
+
+// Derived from hb-ot-shape.h
+
+// FFI:
 
 #[cfg(hb_not_implemented)]
 #[repr(C)]
 struct hb_ot_layout_glyph_sequence_t {
-  before: *const hb_codepoint_t,
+  before: *const Codepoint,
   before_length: libc::c_uint,
-  input: *const hb_codepoint_t,
+  input: *const Codepoint,
   input_length: libc::c_uint,
-  after: *const hb_codepoint_t,
+  after: *const Codepoint,
   after_length: libc::c_uint,
 }
 
 #[cfg(hb_not_implemented)]
-type hb_ot_layout_glyph_sequence_func_t = Option<extern "C" fn(hb_font_t    *font,
-                                                               table_tag: hb_tag_t,
+type hb_ot_layout_glyph_sequence_func_t = Option<extern "C" fn(font: *mut hb_font_t,
+                                                               table_tag: Tag,
                                                                lookup_index: libc::c_uint,
                                                                sequence: *const hb_ot_layout_glyph_sequence_t,
                                                                user_data: *mut libc::c_void) -> hb_bool_t>;
 
-#[cfg(hb_not_implemented)]
-fn Xhb_ot_layout_lookup_enumerate_sequences(face: *mut hb_face_t,
-                                            table_tag: hb_tag_t,
-                                            lookup_index: libc::c_uint,
-                                            callback: hb_ot_layout_glyph_sequence_func_t,
-                                            user_data: *mut libc::c_void);
+extern "C" {
+    fn hb_ot_font_set_funcs(font: *mut hb_font_t);
 
+    fn hb_ot_layout_has_glyph_classes(face: *mut hb_face_t) -> hb_bool_t;
 
-/*
- * GSUB
- */
+    fn hb_ot_layout_get_glyph_class(face: *mut hb_face_t,
+                                    glyph: Codepoint) -> libc::c_uint;
 
-fn hb_ot_layout_has_substitution(face: *mut hb_face_t) -> hb_bool_t;
+    fn hb_ot_layout_get_glyphs_in_class(face: *mut hb_face_t,
+                                        klass: libc::c_uint,
+                                        glyphs: *mut hb_set_t /* out */);
 
-fn hb_ot_layout_lookup_would_substitute(face: *mut hb_face_t,
-                                        lookup_index: libc::c_uint,
-                                        glyphs: *const hb_codepoint_t,
-                                        glyphs_length: libc::c_uint,
-                                        zero_context: hb_bool_t) -> hb_bool_t;
+    fn hb_ot_layout_get_attach_points(face: *mut hb_face_t,
+                                      glyph: Codepoint,
+                                      start_offset: libc::c_uint,
+                                      point_count: *mut libc::c_uint /* in/out */,
+                                      point_array: *mut libc::c_uint /* out */) -> libc::c_uint;
 
-fn hb_ot_layout_lookup_substitute_closure(face: *mut hb_face_t,
-                                          lookup_index: libc::c_uint,
-                                          glyphs: *mut hb_set_t,
-                                          /* TODO(harfbuzz) inclusive: hb_bool_t */);
+    fn hb_ot_layout_get_ligature_carets(font: *mut hb_font_t,
+                                        direction: Direction,
+                                        glyph: Codepoint,
+                                        start_offset: libc::c_uint,
+                                        caret_count: *mut libc::c_uint /* IN/OUT */,
+                                        caret_array: *mut Position /* OUT */) -> libc::c_uint;
 
-#[cfg(hb_not_implemented)]
-/// Note: You better have GDEF when using this API, or marks won't do much.
-fn Xhb_ot_layout_lookup_substitute(font: *mut hb_font_t,
-                                   lookup_index: libc::c_uint,
-                                   sequence: *const hb_ot_layout_glyph_sequence_t,
-                                   out_size: libc::c_uint,
-                                   glyphs_out: *const hb_codepoint_t, /* OUT */
-                                   clusters_out: *mut libc::c_uint, /* OUT */
-                                   out_length: *mut libc::c_uint /* OUT */) -> hb_bool_t;
+    fn hb_ot_layout_table_get_script_tags(face: *mut hb_face_t,
+                                          table_tag: Tag,
+                                          start_offset: libc::c_uint,
+                                          script_count: *mut libc::c_uint /* IN/OUT */,
+                                          script_tags: *mut Tag /* OUT */) -> libc::c_uint;
+
+    fn hb_ot_layout_table_find_script(face: *mut hb_face_t,
+                                      table_tag: Tag,
+                                      script_tag: Tag,
+                                      script_index: *mut libc::c_uint) -> hb_bool_t;
 
+    fn hb_ot_layout_table_get_feature_tags(face: *mut hb_face_t,
+                                           table_tag: Tag,
+                                           start_offset: libc::c_uint,
+                                           feature_count: *mut libc::c_uint /* IN/OUT */,
+                                           feature_tags: *mut Tag /* OUT */) -> libc::c_uint;
 
-/*
- * GPOS
- */
+    fn hb_ot_layout_script_get_language_tags(face: *mut hb_face_t,
+                                             table_tag: Tag,
+                                             script_index: libc::c_uint,
+                                             start_offset: libc::c_uint,
+                                             language_count: *mut libc::c_uint /* IN/OUT */,
+                                             language_tags: *mut Tag /* OUT */) -> libc::c_uint;
+
+    fn hb_ot_layout_script_select_language(face: *mut hb_face_t,
+                                           table_tag: Tag,
+                                           script_index: libc::c_uint,
+                                           language_count: libc::c_uint,
+                                           language_tags: *const Tag,
+                                           language_index: *mut libc::c_uint /* OUT */)
+                                        -> hb_bool_t;
+
+    fn hb_ot_layout_language_get_required_feature(face: *mut hb_face_t,
+                                                  table_tag: Tag,
+                                                  script_index: libc::c_uint,
+                                                  language_index: libc::c_uint,
+                                                  feature_index: *mut libc::c_uint,
+                                                  feature_tag: *mut Tag) -> hb_bool_t;
+
+    fn hb_ot_layout_language_get_feature_indexes(face: *mut hb_face_t,
+                                                 table_tag: Tag,
+                                                 script_index: libc::c_uint,
+                                                 language_index: libc::c_uint,
+                                                 start_offset: libc::c_uint,
+                                                 feature_count: *mut libc::c_uint /* IN/OUT */,
+                                                 feature_indexes: *mut libc::c_uint /* OUT */) -> libc::c_uint;
+
+    fn hb_ot_layout_language_get_feature_tags(face: *mut hb_face_t,
+                                              table_tag: Tag,
+                                              script_index: libc::c_uint,
+                                              language_index: libc::c_uint,
+                                              start_offset: libc::c_uint,
+                                              feature_count: *mut libc::c_uint /* IN/OUT */,
+                                              feature_tags: *mut Tag /* OUT */) -> libc::c_uint;
 
-fn hb_ot_layout_has_positioning(face: *mut hb_face_t) -> hb_bool_t;
+    fn hb_ot_layout_language_find_feature(face: *mut hb_face_t,
+                                          table_tag: Tag,
+                                          script_index: libc::c_uint,
+                                          language_index: libc::c_uint,
+                                          feature_tag: Tag,
+                                          feature_index: *mut libc::c_uint) -> hb_bool_t;
 
-#[cfg(hb_not_implemented)]
-/// Note: You better have GDEF when using this API, or marks won't do much.
-fn Xhb_ot_layout_lookup_position(font: *mut hb_font_t,
-                                 lookup_index: libc::c_uint,
-                                 sequence: *const hb_ot_layout_glyph_sequence_t,
-                                 positions: *mut hb_glyph_position_t /* IN / OUT */) -> hb_bool_t;
-
-/* Optical 'size' feature info.  Returns true if found.
- * http://www.microsoft.com/typography/otspec/features_pt.htm#size */
-fn hb_ot_layout_get_size_params(face: *mut hb_face_t,
-                                design_size: *mut libc::c_uint,       /* OUT.  May be NULL */
-                                subfamily_id: *mut libc::c_uint,      /* OUT.  May be NULL */
-                                subfamily_name_id: *mut libc::c_uint, /* OUT.  May be NULL */
-                                range_start: *mut libc::c_uint,       /* OUT.  May be NULL */
-                                range_end: *mut libc::c_uint          /* OUT.  May be NULL */) -> hb_bool_t;
+    fn hb_ot_layout_table_get_lookup_count(face: *mut hb_face_t,
+                                           table_tag: Tag) -> libc::c_uint;
+
+    fn hb_ot_layout_feature_get_lookups(face: *mut hb_face_t,
+                                        table_tag: Tag,
+                                        feature_index: libc::c_uint,
+                                        start_offset: libc::c_uint,
+                                        lookup_count: *mut libc::c_uint /* IN/OUT */,
+                                        lookup_indexes: *mut libc::c_uint /* OUT */) -> libc::c_uint;
+
+    fn hb_ot_layout_collect_lookups(face: *mut hb_face_t,
+                                    table_tag: Tag,
+                                    scripts: *const Tag,
+                                    languages: *const Tag,
+                                    features: *const Tag,
+                                    lookup_indexes: *mut hb_set_t /* OUT */);
+
+    fn hb_ot_layout_lookup_collect_glyphs(face: *mut hb_face_t,
+                                          table_tag: Tag,
+                                          lookup_index: libc::c_uint,
+                                          glyphs_before: *mut hb_set_t, /* OUT. May be NULL */
+                                          glyphs_input: *mut hb_set_t,  /* OUT. May be NULL */
+                                          glyphs_after: *mut hb_set_t,  /* OUT. May be NULL */
+                                          glyphs_output: *mut hb_set_t  /* OUT. May be NULL */);
+
+    fn hb_ot_layout_lookup_would_substitute(face: *mut hb_face_t,
+                                            lookup_index: libc::c_uint,
+                                            glyphs: *const Codepoint,
+                                            glyphs_length: libc::c_uint,
+                                            zero_context: hb_bool_t) -> hb_bool_t;
+
+    // XXX XXX XXX XXX XXX ↓ scratch area ↓ XXX XXX XXX XXX XXX
+
+    /// Like find_script, but takes zero-terminated array of scripts to test
+    fn hb_ot_layout_table_choose_script(face: *mut hb_face_t,
+                                        table_tag: Tag,
+                                        script_tags: *const Tag,
+                                        script_index: *mut libc::c_uint,
+                                        chosen_script: *mut Tag) -> hb_bool_t;
+
+    fn hb_ot_layout_script_find_language(face: *mut hb_face_t,
+                                         table_tag: Tag,
+                                         script_index: libc::c_uint,
+                                         language_tag: Tag,
+                                         language_index: *mut libc::c_uint) -> hb_bool_t;
+
+    fn hb_ot_layout_language_get_required_feature_index(face: *mut hb_face_t,
+                                                        table_tag: Tag,
+                                                        script_index: libc::c_uint,
+                                                        language_index: libc::c_uint,
+                                                        feature_index: *mut libc::c_uint) -> hb_bool_t;
+
+
+    #[cfg(hb_not_implemented)]
+    fn Xhb_ot_layout_lookup_enumerate_sequences(face: *mut hb_face_t,
+                                                table_tag: Tag,
+                                                lookup_index: libc::c_uint,
+                                                callback: hb_ot_layout_glyph_sequence_func_t,
+                                                user_data: *mut libc::c_void);
+
+
+    /*
+     * GSUB
+     */
+
+    fn hb_ot_layout_has_substitution(face: *mut hb_face_t) -> hb_bool_t;
+
+
+    fn hb_ot_layout_lookup_substitute_closure(face: *mut hb_face_t,
+                                              lookup_index: libc::c_uint,
+                                              glyphs: *mut hb_set_t,
+                                              /* TODO(harfbuzz) inclusive: hb_bool_t */);
+
+    #[cfg(hb_not_implemented)]
+    /// Note: You better have GDEF when using this API, or marks won't do much.
+    fn Xhb_ot_layout_lookup_substitute(font: *mut hb_font_t,
+                                       lookup_index: libc::c_uint,
+                                       sequence: *const hb_ot_layout_glyph_sequence_t,
+                                       out_size: libc::c_uint,
+                                       glyphs_out: *const Codepoint, /* OUT */
+                                       clusters_out: *mut libc::c_uint, /* OUT */
+                                       out_length: *mut libc::c_uint /* OUT */) -> hb_bool_t;
+
+
+    /*
+     * GPOS
+     */
+
+    fn hb_ot_layout_has_positioning(face: *mut hb_face_t) -> hb_bool_t;
+
+    #[cfg(hb_not_implemented)]
+    /// Note: You better have GDEF when using this API, or marks won't do much.
+    fn Xhb_ot_layout_lookup_position(font: *mut hb_font_t,
+                                     lookup_index: libc::c_uint,
+                                     sequence: *const hb_ot_layout_glyph_sequence_t,
+                                     positions: *mut crate::buffer::GlyphPosition /* IN / OUT */) -> hb_bool_t;
+
+    /* Optical 'size' feature info.  Returns true if found.
+     * http://www.microsoft.com/typography/otspec/features_pt.htm#size */
+    fn hb_ot_layout_get_size_params(face: *mut hb_face_t,
+                                    design_size: *mut libc::c_uint,       /* OUT.  May be NULL */
+                                    subfamily_id: *mut libc::c_uint,      /* OUT.  May be NULL */
+                                    subfamily_name_id: *mut libc::c_uint, /* OUT.  May be NULL */
+                                    range_start: *mut libc::c_uint,       /* OUT.  May be NULL */
+                                    range_end: *mut libc::c_uint          /* OUT.  May be NULL */) -> hb_bool_t;
+}
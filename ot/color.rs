@@ -0,0 +1,225 @@
+//! Derived from `hb-ot-color.h`: the color palettes of the `CPAL` table, the color glyphs of the
+//! `COLR` table, and the images of the `SVG `, `CBDT` and `sbix` tables. For painting `COLR`
+//! version 1 glyphs, see `Font::paint_glyph`.
+
+use std::vec;
+
+use crate::blob::{Blob, hb_blob_t};
+use crate::common::{Codepoint, Color, hb_bool_t, collect_array};
+use crate::face::{Face, hb_face_t};
+use crate::font::{Font, hb_font_t};
+use super::var::NAME_ID_INVALID;
+
+bitflags! {
+    /// What a palette is meant for.
+    ///
+    /// Equivalent to `hb_ot_color_palette_flags_t`.
+    #[repr(C)]
+    pub struct ColorPaletteFlags: u32 {
+        /// Equivalent to `HB_OT_COLOR_PALETTE_FLAG_DEFAULT`.
+        const DEFAULT = 0x00000000;
+        /// Equivalent to `HB_OT_COLOR_PALETTE_FLAG_USABLE_WITH_LIGHT_BACKGROUND`.
+        const USABLE_WITH_LIGHT_BACKGROUND = 0x00000001;
+        /// Equivalent to `HB_OT_COLOR_PALETTE_FLAG_USABLE_WITH_DARK_BACKGROUND`.
+        const USABLE_WITH_DARK_BACKGROUND = 0x00000002;
+    }
+}
+
+/// One of the palettes in a face’s `CPAL` table.
+///
+/// There is no equivalent in HarfBuzz, which has a function for each field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorPalette {
+    /// The palette’s index, for `Font::paint_glyph`.
+    pub index: u32,
+    /// The `name` table entry with the palette’s name (“Dark”), if it has one.
+    pub name_id: Option<u32>,
+    pub flags: ColorPaletteFlags,
+    /// The palette’s entries, which `ColorLayer::color_index` and the paint API index into.
+    pub colors: Vec<Color>,
+}
+
+/// One layer of a `COLR` version 0 glyph: a glyph to fill with a color from the palette.
+///
+/// Equivalent to `hb_ot_color_layer_t`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ColorLayer {
+    pub glyph: Codepoint,
+    /// The palette entry to fill the glyph with, or `None` for the foreground (text) color.
+    pub color_index: Option<u32>,
+}
+
+impl<'a> Face<'a> {
+    /// Whether the face has a `CPAL` table.
+    ///
+    /// Equivalent to `hb_ot_color_has_palettes`.
+    pub fn has_color_palettes(&self) -> bool {
+        unsafe {
+            hb_ot_color_has_palettes(self.ptr) != 0
+        }
+    }
+
+    /// The face’s color palettes, the default first; empty if it has no `CPAL` table.
+    ///
+    /// Equivalent to `hb_ot_color_palette_get_count` and the `hb_ot_color_palette_get_*`
+    /// functions.
+    pub fn color_palettes(&self) -> vec::IntoIter<ColorPalette> {
+        unsafe {
+            let count = hb_ot_color_palette_get_count(self.ptr);
+            (0..count).map(|index| {
+                let name_id = hb_ot_color_palette_get_name_id(self.ptr, index);
+                ColorPalette {
+                    index,
+                    name_id: if name_id == NAME_ID_INVALID { None } else { Some(name_id) },
+                    flags: ColorPaletteFlags::from_bits_truncate(
+                        hb_ot_color_palette_get_flags(self.ptr, index)),
+                    colors: collect_array(|start_offset, color_count, colors| {
+                        hb_ot_color_palette_get_colors(self.ptr, index, start_offset, color_count,
+                                                       colors)
+                    }),
+                }
+            }).collect::<Vec<_>>().into_iter()
+        }
+    }
+
+    /// The `name` table entry naming a palette entry (the same in every palette), e.g. “Outline”,
+    /// if it has one.
+    ///
+    /// Equivalent to `hb_ot_color_palette_color_get_name_id`.
+    pub fn color_palette_entry_name_id(&self, color_index: u32) -> Option<u32> {
+        let name_id = unsafe { hb_ot_color_palette_color_get_name_id(self.ptr, color_index) };
+        if name_id == NAME_ID_INVALID { None } else { Some(name_id) }
+    }
+
+    /// Whether the face has `COLR` version 0 layers.
+    ///
+    /// Equivalent to `hb_ot_color_has_layers`.
+    pub fn has_color_layers(&self) -> bool {
+        unsafe {
+            hb_ot_color_has_layers(self.ptr) != 0
+        }
+    }
+
+    /// The layers of a `COLR` version 0 glyph, bottom first, to be drawn one over another; empty
+    /// if it isn’t one.
+    ///
+    /// Equivalent to `hb_ot_color_glyph_get_layers`.
+    pub fn color_glyph_layers(&self, glyph: Codepoint) -> vec::IntoIter<ColorLayer> {
+        unsafe {
+            collect_array(|start_offset, layer_count, layers| {
+                hb_ot_color_glyph_get_layers(self.ptr, glyph, start_offset, layer_count, layers)
+            })
+        }.into_iter().map(ColorLayer::from).collect::<Vec<_>>().into_iter()
+    }
+
+    /// Whether the face has `COLR` version 1 paint data.
+    ///
+    /// Equivalent to `hb_ot_color_has_paint`.
+    pub fn has_color_paint(&self) -> bool {
+        unsafe {
+            hb_ot_color_has_paint(self.ptr) != 0
+        }
+    }
+
+    /// Whether the glyph is a `COLR` version 1 glyph, to be drawn with `Font::paint_glyph`.
+    ///
+    /// Equivalent to `hb_ot_color_glyph_has_paint`.
+    pub fn glyph_has_color_paint(&self, glyph: Codepoint) -> bool {
+        unsafe {
+            hb_ot_color_glyph_has_paint(self.ptr, glyph) != 0
+        }
+    }
+
+    /// Whether the face has an `SVG ` table.
+    ///
+    /// Equivalent to `hb_ot_color_has_svg`.
+    pub fn has_svg(&self) -> bool {
+        unsafe {
+            hb_ot_color_has_svg(self.ptr) != 0
+        }
+    }
+
+    /// The SVG document with the glyph in it (as the element with the id `glyph` followed by the
+    /// glyph ID), if it has one. Several glyphs may share a document; it may be gzipped.
+    ///
+    /// Equivalent to `hb_ot_color_glyph_reference_svg`.
+    pub fn glyph_svg_document(&self, glyph: Codepoint) -> Option<Blob<'a>> {
+        let blob = unsafe { Blob::from(hb_ot_color_glyph_reference_svg(self.ptr, glyph)) };
+        if blob.is_empty() { None } else { Some(blob) }
+    }
+
+    /// Whether the face has PNG glyph images, in a `CBDT` or `sbix` table.
+    ///
+    /// Equivalent to `hb_ot_color_has_png`.
+    pub fn has_png(&self) -> bool {
+        unsafe {
+            hb_ot_color_has_png(self.ptr) != 0
+        }
+    }
+}
+
+impl<'a> Font<'a> {
+    /// The glyph’s PNG image, if it has one, from the strike best suited to the font’s ppem
+    /// (`Font::ppem`): the smallest strike at least that big, or else the biggest there is. With
+    /// no ppem set, that’s the biggest.
+    ///
+    /// Equivalent to `hb_ot_color_glyph_reference_png`.
+    pub fn glyph_png(&self, glyph: Codepoint) -> Option<Blob<'a>> {
+        let blob = unsafe { Blob::from(hb_ot_color_glyph_reference_png(self.ptr, glyph)) };
+        if blob.is_empty() { None } else { Some(blob) }
+    }
+}
+
+// FFI:
+
+/// The color index that means the foreground color.
+const FOREGROUND_COLOR_INDEX: libc::c_uint = 0xFFFF;
+
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+struct hb_ot_color_layer_t {
+    glyph: Codepoint,
+    color_index: libc::c_uint,
+}
+
+impl From<hb_ot_color_layer_t> for ColorLayer {
+    fn from(layer: hb_ot_color_layer_t) -> ColorLayer {
+        ColorLayer {
+            glyph: layer.glyph,
+            color_index: if layer.color_index == FOREGROUND_COLOR_INDEX {
+                None
+            } else {
+                Some(layer.color_index)
+            },
+        }
+    }
+}
+
+extern "C" {
+    fn hb_ot_color_has_palettes(face: *mut hb_face_t) -> hb_bool_t;
+    fn hb_ot_color_palette_get_count(face: *mut hb_face_t) -> libc::c_uint;
+    fn hb_ot_color_palette_get_name_id(face: *mut hb_face_t,
+                                       palette_index: libc::c_uint) -> libc::c_uint;
+    fn hb_ot_color_palette_color_get_name_id(face: *mut hb_face_t,
+                                             color_index: libc::c_uint) -> libc::c_uint;
+    fn hb_ot_color_palette_get_flags(face: *mut hb_face_t, palette_index: libc::c_uint) -> u32;
+    fn hb_ot_color_palette_get_colors(face: *mut hb_face_t, palette_index: libc::c_uint,
+                                      start_offset: libc::c_uint,
+                                      color_count: *mut libc::c_uint /* in/out */,
+                                      colors: *mut Color /* out */) -> libc::c_uint;
+
+    fn hb_ot_color_has_layers(face: *mut hb_face_t) -> hb_bool_t;
+    fn hb_ot_color_glyph_get_layers(face: *mut hb_face_t, glyph: Codepoint,
+                                    start_offset: libc::c_uint,
+                                    layer_count: *mut libc::c_uint /* in/out */,
+                                    layers: *mut hb_ot_color_layer_t /* out */) -> libc::c_uint;
+
+    fn hb_ot_color_has_paint(face: *mut hb_face_t) -> hb_bool_t;
+    fn hb_ot_color_glyph_has_paint(face: *mut hb_face_t, glyph: Codepoint) -> hb_bool_t;
+
+    fn hb_ot_color_has_svg(face: *mut hb_face_t) -> hb_bool_t;
+    fn hb_ot_color_glyph_reference_svg(face: *mut hb_face_t, glyph: Codepoint) -> *mut hb_blob_t;
+
+    fn hb_ot_color_has_png(face: *mut hb_face_t) -> hb_bool_t;
+    fn hb_ot_color_glyph_reference_png(font: *mut hb_font_t, glyph: Codepoint) -> *mut hb_blob_t;
+}
@@ -0,0 +1,204 @@
+//! Derived from `hb-ot-var.h`: what a variable font’s `fvar` and `avar` tables say about its axes
+//! and named instances.
+
+use std::vec;
+
+use crate::common::{Tag, Variation, hb_bool_t, collect_array};
+use crate::face::{Face, hb_face_t};
+
+/// Equivalent to `HB_OT_NAME_ID_INVALID`.
+pub(super) const NAME_ID_INVALID: u32 = 0xFFFF;
+
+/// One of a variable font’s axes of variation, such as weight or width.
+///
+/// Equivalent to `hb_ot_var_axis_info_t`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VariationAxis {
+    /// The axis’s index in the `fvar` table, which is also its place in normalized coordinates.
+    pub index: u32,
+    pub tag: Tag,
+    /// The `name` table entry with the axis’s name, for showing to users.
+    pub name_id: u32,
+    /// Whether the font asks that the axis not be shown to users.
+    pub hidden: bool,
+    pub min_value: f32,
+    pub default_value: f32,
+    pub max_value: f32,
+}
+
+impl From<hb_ot_var_axis_info_t> for VariationAxis {
+    fn from(info: hb_ot_var_axis_info_t) -> VariationAxis {
+        VariationAxis {
+            index: info.axis_index,
+            tag: info.tag,
+            name_id: info.name_id,
+            hidden: info.flags & HB_OT_VAR_AXIS_FLAG_HIDDEN != 0,
+            min_value: info.min_value,
+            default_value: info.default_value,
+            max_value: info.max_value,
+        }
+    }
+}
+
+/// A position in the design space that the font gives a name, such as “Bold Condensed”.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedInstance {
+    /// The instance’s index in the `fvar` table, for `Font::set_named_instance`.
+    pub index: u32,
+    /// The `name` table entry with the instance’s subfamily name (“Bold Condensed”).
+    pub subfamily_name_id: u32,
+    /// The `name` table entry with the instance’s PostScript name, if it has one.
+    pub postscript_name_id: Option<u32>,
+    /// The instance’s design coordinates, one per axis.
+    pub coords: Vec<f32>,
+}
+
+impl<'a> Face<'a> {
+    /// Whether the face is a variable font, i.e. has an `fvar` table.
+    ///
+    /// Equivalent to `hb_ot_var_has_data`.
+    pub fn has_variation_data(&self) -> bool {
+        unsafe {
+            hb_ot_var_has_data(self.ptr) != 0
+        }
+    }
+
+    /// The face’s axes of variation, in `fvar` order; empty if it isn’t a variable font.
+    ///
+    /// Equivalent to `hb_ot_var_get_axis_infos`.
+    pub fn variation_axes(&self) -> vec::IntoIter<VariationAxis> {
+        unsafe {
+            collect_array(|start_offset, axes_count, axes_array| {
+                hb_ot_var_get_axis_infos(self.ptr, start_offset, axes_count, axes_array)
+            })
+        }.into_iter().map(VariationAxis::from).collect::<Vec<_>>().into_iter()
+    }
+
+    /// The axis with the given tag, if the face has one.
+    ///
+    /// Equivalent to `hb_ot_var_find_axis_info`.
+    pub fn find_variation_axis(&self, tag: Tag) -> Option<VariationAxis> {
+        unsafe {
+            let mut info = hb_ot_var_axis_info_t::default();
+            if hb_ot_var_find_axis_info(self.ptr, tag, &mut info) != 0 {
+                Some(info.into())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// The face’s named instances, in `fvar` order.
+    ///
+    /// Equivalent to `hb_ot_var_get_named_instance_count` and the `hb_ot_var_named_instance_*`
+    /// functions.
+    pub fn named_instances(&self) -> vec::IntoIter<NamedInstance> {
+        unsafe {
+            let count = hb_ot_var_get_named_instance_count(self.ptr);
+            (0..count).map(|index| {
+                let postscript_name_id =
+                    hb_ot_var_named_instance_get_postscript_name_id(self.ptr, index);
+                NamedInstance {
+                    index,
+                    subfamily_name_id:
+                        hb_ot_var_named_instance_get_subfamily_name_id(self.ptr, index),
+                    postscript_name_id: if postscript_name_id == NAME_ID_INVALID {
+                        None
+                    } else {
+                        Some(postscript_name_id)
+                    },
+                    coords: collect_array(|start_offset, coords_length, coords| {
+                        // There’s no start_offset here, but collect_array only asks for the lot.
+                        debug_assert_eq!(start_offset, 0);
+                        hb_ot_var_named_instance_get_design_coords(self.ptr, index, coords_length,
+                                                                   coords)
+                    }),
+                }
+            }).collect::<Vec<_>>().into_iter()
+        }
+    }
+
+    /// Converts axis settings in design units (e.g. `wght=650`) to normalized coordinates, one per
+    /// axis in `fvar` order, applying the `avar` table’s mapping if there is one. Axes not
+    /// mentioned are at their defaults, i.e. 0.
+    ///
+    /// The result is what `Font::set_var_coords_normalized` takes; `Font::set_variations` does
+    /// this itself.
+    ///
+    /// Equivalent to `hb_ot_var_normalize_variations`.
+    pub fn normalize_variations(&self, variations: &[Variation]) -> Vec<i32> {
+        unsafe {
+            let mut coords = vec![0; hb_ot_var_get_axis_count(self.ptr) as usize];
+            hb_ot_var_normalize_variations(self.ptr, variations.as_ptr(),
+                                           variations.len() as libc::c_uint,
+                                           coords.as_mut_ptr(), coords.len() as libc::c_uint);
+            coords
+        }
+    }
+
+    /// Converts design coordinates, one per axis in `fvar` order (as in `NamedInstance::coords`),
+    /// to normalized coordinates. Missing trailing axes are at their defaults.
+    ///
+    /// Equivalent to `hb_ot_var_normalize_coords`.
+    pub fn normalize_coords(&self, design_coords: &[f32]) -> Vec<i32> {
+        unsafe {
+            let axis_count = hb_ot_var_get_axis_count(self.ptr) as usize;
+            let mut design_coords = design_coords.to_vec();
+            let defaults = self.variation_axes().skip(design_coords.len());
+            design_coords.extend(defaults.map(|axis| axis.default_value));
+            design_coords.truncate(axis_count);
+            let mut coords = vec![0; axis_count];
+            hb_ot_var_normalize_coords(self.ptr, axis_count as libc::c_uint,
+                                       design_coords.as_ptr(), coords.as_mut_ptr());
+            coords
+        }
+    }
+}
+
+// FFI:
+
+/// Equivalent to `HB_OT_VAR_AXIS_FLAG_HIDDEN`, of `hb_ot_var_axis_flags_t`.
+const HB_OT_VAR_AXIS_FLAG_HIDDEN: libc::c_uint = 0x1;
+
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+struct hb_ot_var_axis_info_t {
+    axis_index: libc::c_uint,
+    tag: Tag,
+    name_id: libc::c_uint,
+    flags: libc::c_uint,
+    min_value: f32,
+    default_value: f32,
+    max_value: f32,
+    reserved: libc::c_uint,
+}
+
+extern "C" {
+    fn hb_ot_var_has_data(face: *mut hb_face_t) -> hb_bool_t;
+    fn hb_ot_var_get_axis_count(face: *mut hb_face_t) -> libc::c_uint;
+    fn hb_ot_var_get_axis_infos(face: *mut hb_face_t, start_offset: libc::c_uint,
+                                axes_count: *mut libc::c_uint /* in/out */,
+                                axes_array: *mut hb_ot_var_axis_info_t /* out */) -> libc::c_uint;
+    fn hb_ot_var_find_axis_info(face: *mut hb_face_t, axis_tag: Tag,
+                                axis_info: *mut hb_ot_var_axis_info_t) -> hb_bool_t;
+    fn hb_ot_var_get_named_instance_count(face: *mut hb_face_t) -> libc::c_uint;
+    fn hb_ot_var_named_instance_get_subfamily_name_id(face: *mut hb_face_t,
+                                                      instance_index: libc::c_uint)
+                                                   -> libc::c_uint;
+    fn hb_ot_var_named_instance_get_postscript_name_id(face: *mut hb_face_t,
+                                                       instance_index: libc::c_uint)
+                                                    -> libc::c_uint;
+    fn hb_ot_var_named_instance_get_design_coords(face: *mut hb_face_t,
+                                                  instance_index: libc::c_uint,
+                                                  coords_length: *mut libc::c_uint /* in/out */,
+                                                  coords: *mut f32 /* out */) -> libc::c_uint;
+    fn hb_ot_var_normalize_variations(face: *mut hb_face_t,
+                                      variations: *const Variation,
+                                      variations_length: libc::c_uint,
+                                      coords: *mut libc::c_int /* out */,
+                                      coords_length: libc::c_uint);
+    fn hb_ot_var_normalize_coords(face: *mut hb_face_t,
+                                  coords_length: libc::c_uint,
+                                  design_coords: *const f32,
+                                  normalized_coords: *mut libc::c_int /* out */);
+}
@@ -0,0 +1,540 @@
+//! Derived from `hb-paint.h`.
+
+use std::mem::ManuallyDrop;
+use std::ptr;
+
+use crate::blob::{Blob, hb_blob_t};
+use crate::common::{Codepoint, Color, Tag, FuncsCache, hb_bool_t, hb_destroy_func_t,
+                    collect_array};
+use crate::font::{Font, GlyphExtents, hb_font_t};
+
+define_boxed_type! {
+    struct PaintFuncs;
+    enum hb_paint_funcs_t;
+    fn hb_paint_funcs_get_empty;
+    fn hb_paint_funcs_reference;
+    fn hb_paint_funcs_destroy;
+    fn hb_paint_funcs_set_user_data;
+    fn hb_paint_funcs_get_user_data;
+    fn hb_paint_funcs_make_immutable;
+    fn hb_paint_funcs_is_immutable;
+}
+
+/// Something color glyphs can be painted into, such as a renderer or a scene graph: see
+/// `Font::paint_glyph`.
+///
+/// Painting is a stack machine. Transforms, clips and groups are pushed and later popped, always
+/// in matching pairs; `color` and the gradients fill the whole of the current clip (under the
+/// current transform); and popping a group composites what was painted since it was pushed onto
+/// what was there before. Coordinates are in the font’s scale (`Font::scale`), with y growing
+/// upwards.
+///
+/// HarfBuzz calls these from C, so they mustn’t panic.
+///
+/// The Rust side of `hb_paint_funcs_t`.
+pub trait PaintSink {
+    /// Applies the affine transform (`xx`, `yx`, `xy`, `yy`, `dx`, `dy`) to everything until the
+    /// matching `pop_transform`: a point (x, y) goes to (xx·x + xy·y + dx, yx·x + yy·y + dy).
+    fn push_transform(&mut self, xx: f32, yx: f32, xy: f32, yy: f32, dx: f32, dy: f32);
+    fn pop_transform(&mut self);
+
+    /// Clips to the outline of a glyph, which can be had from `font.draw_glyph(glyph, …)`, until
+    /// the matching `pop_clip`.
+    fn push_clip_glyph(&mut self, glyph: Codepoint, font: &Font<'_>);
+    /// Clips to a rectangle until the matching `pop_clip`.
+    fn push_clip_rectangle(&mut self, xmin: f32, ymin: f32, xmax: f32, ymax: f32);
+    fn pop_clip(&mut self);
+
+    /// Fills the clip with a solid color. If `is_foreground`, the font asked for the foreground
+    /// (text) color, and `color` is the one passed to `Font::paint_glyph`, with whatever alpha the
+    /// font applied to it.
+    fn color(&mut self, is_foreground: bool, color: Color);
+
+    /// Fills the clip with a linear gradient: the color line runs from `p0` to `p1`, and `p2` sets
+    /// the direction of its lines of equal color, which run parallel to the line from `p0` to it.
+    /// Points are (x, y).
+    fn linear_gradient(&mut self, color_line: &ColorLine, p0: (f32, f32), p1: (f32, f32),
+                       p2: (f32, f32));
+    /// Fills the clip with a radial gradient between the circle centred on `c0` of radius `r0`
+    /// and the one centred on `c1` of radius `r1`. Points are (x, y).
+    fn radial_gradient(&mut self, color_line: &ColorLine, c0: (f32, f32), r0: f32,
+                       c1: (f32, f32), r1: f32);
+    /// Fills the clip with a sweep gradient around `center`, (x, y), between the angles given in
+    /// radians, counter-clockwise from the positive x axis.
+    fn sweep_gradient(&mut self, color_line: &ColorLine, center: (f32, f32), start_angle: f32,
+                      end_angle: f32);
+
+    /// Starts painting onto a new, transparent layer.
+    fn push_group(&mut self);
+    /// Composites the layer started by the matching `push_group` onto what was there before.
+    fn pop_group(&mut self, mode: CompositeMode);
+
+    /// Paints an image (a PNG or SVG glyph from another table, or BGRA pixels) over the glyph’s
+    /// `extents`, returning whether it did. `format` is `png `, `svg ` or `BGRA`; `slant` is the
+    /// font’s synthetic slant, to skew the image by.
+    ///
+    /// The default paints nothing, so HarfBuzz carries on without the image.
+    fn image(&mut self, image: &Blob<'_>, width: u32, height: u32, format: Tag, slant: f32,
+             extents: Option<&GlyphExtents>) -> bool {
+        let _ = (image, width, height, format, slant, extents);
+        false
+    }
+
+    /// Overrides the color of a palette entry, for a custom palette; `None` uses the palette’s.
+    ///
+    /// The default overrides nothing.
+    fn custom_palette_color(&mut self, color_index: u32) -> Option<Color> {
+        let _ = color_index;
+        None
+    }
+}
+
+/// The color stops of a gradient, and what it does beyond them.
+///
+/// Collected from an `hb_color_line_t`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorLine {
+    /// The stops, in the font’s order, which is not necessarily by offset.
+    pub stops: Vec<ColorStop>,
+    pub extend: PaintExtend,
+}
+
+/// Equivalent to `hb_color_stop_t`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ColorStop {
+    /// Where the stop is along the color line, usually (but not necessarily) from 0 to 1.
+    pub offset: f32,
+    /// Whether the font asked for the foreground color here; see `PaintSink::color`.
+    pub is_foreground: bool,
+    /// The color, not premultiplied, though gradients are to be interpolated premultiplied.
+    pub color: Color,
+}
+
+/// What a gradient does outside the offsets of its color stops.
+///
+/// Equivalent to `hb_paint_extend_t`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PaintExtend {
+    /// Continue with the first or last stop’s color. `HB_PAINT_EXTEND_PAD`
+    Pad,
+    /// Repeat the color line. `HB_PAINT_EXTEND_REPEAT`
+    Repeat,
+    /// Repeat the color line, reversing every other repetition. `HB_PAINT_EXTEND_REFLECT`
+    Reflect,
+}
+
+/// How a group is composited onto what was there before (`PaintSink::pop_group`): the Porter-Duff
+/// operators, then the separable and non-separable blend modes.
+///
+/// Equivalent to `hb_paint_composite_mode_t`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CompositeMode {
+    Clear,
+    Src,
+    Dest,
+    SrcOver,
+    DestOver,
+    SrcIn,
+    DestIn,
+    SrcOut,
+    DestOut,
+    SrcAtop,
+    DestAtop,
+    Xor,
+    Plus,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Multiply,
+    HslHue,
+    HslSaturation,
+    HslColor,
+    HslLuminosity,
+}
+
+impl<'a> Font<'a> {
+    /// Paints the glyph into `sink`: a `COLR` glyph in the colors of the palette `palette_index`
+    /// (0 being the default; see `Face::color_palettes`) with `foreground` for the text color, a
+    /// bitmap glyph as an image, or failing both, the outline filled with `foreground`.
+    ///
+    /// Equivalent to `hb_font_paint_glyph`.
+    pub fn paint_glyph<S: PaintSink>(&self, glyph: Codepoint, sink: &mut S, palette_index: u32,
+                                     foreground: Color) {
+        unsafe {
+            let pfuncs = PaintFuncs::for_sink::<S>();
+            hb_font_paint_glyph(self.ptr, glyph, pfuncs.ptr, sink as *mut S as *mut libc::c_void,
+                                palette_index, foreground);
+        }
+    }
+}
+
+static PAINT_FUNCS: FuncsCache<[*const (); 13], PaintFuncs> = FuncsCache::new();
+
+impl PaintFuncs {
+    /// An immutable `PaintFuncs` whose every func calls through to the `S` passed as paint data,
+    /// made the first time it’s wanted for `S` and cached after that.
+    unsafe fn for_sink<S: PaintSink>() -> PaintFuncs {
+        let key = [push_transform_func::<S> as *const (), pop_transform_func::<S> as *const (),
+                   push_clip_glyph_func::<S> as *const (),
+                   push_clip_rectangle_func::<S> as *const (), pop_clip_func::<S> as *const (),
+                   color_func::<S> as *const (), image_func::<S> as *const (),
+                   linear_gradient_func::<S> as *const (),
+                   radial_gradient_func::<S> as *const (), sweep_gradient_func::<S> as *const (),
+                   push_group_func::<S> as *const (), pop_group_func::<S> as *const (),
+                   custom_palette_color_func::<S> as *const ()];
+        PAINT_FUNCS.get(key, || PaintFuncs::create_for_sink::<S>())
+    }
+
+    unsafe fn create_for_sink<S: PaintSink>() -> PaintFuncs {
+        let mut pfuncs = PaintFuncs::from(hb_paint_funcs_create());
+        let p = pfuncs.ptr;
+        let null = ptr::null_mut();
+        hb_paint_funcs_set_push_transform_func(p, Some(push_transform_func::<S>), null, None);
+        hb_paint_funcs_set_pop_transform_func(p, Some(pop_transform_func::<S>), null, None);
+        hb_paint_funcs_set_push_clip_glyph_func(p, Some(push_clip_glyph_func::<S>), null, None);
+        hb_paint_funcs_set_push_clip_rectangle_func(p, Some(push_clip_rectangle_func::<S>), null,
+                                                    None);
+        hb_paint_funcs_set_pop_clip_func(p, Some(pop_clip_func::<S>), null, None);
+        hb_paint_funcs_set_color_func(p, Some(color_func::<S>), null, None);
+        hb_paint_funcs_set_image_func(p, Some(image_func::<S>), null, None);
+        hb_paint_funcs_set_linear_gradient_func(p, Some(linear_gradient_func::<S>), null, None);
+        hb_paint_funcs_set_radial_gradient_func(p, Some(radial_gradient_func::<S>), null, None);
+        hb_paint_funcs_set_sweep_gradient_func(p, Some(sweep_gradient_func::<S>), null, None);
+        hb_paint_funcs_set_push_group_func(p, Some(push_group_func::<S>), null, None);
+        hb_paint_funcs_set_pop_group_func(p, Some(pop_group_func::<S>), null, None);
+        hb_paint_funcs_set_custom_palette_color_func(p, Some(custom_palette_color_func::<S>),
+                                                     null, None);
+        pfuncs.make_immutable();
+        pfuncs
+    }
+}
+
+impl ColorLine {
+    unsafe fn from_raw(color_line: *mut hb_color_line_t) -> ColorLine {
+        ColorLine {
+            stops: collect_array(|start, count, color_stops| {
+                hb_color_line_get_color_stops(color_line, start, count, color_stops)
+            }).into_iter().map(|stop: hb_color_stop_t| ColorStop {
+                offset: stop.offset,
+                is_foreground: stop.is_foreground != 0,
+                color: stop.color,
+            }).collect(),
+            extend: PaintExtend::from_raw(hb_color_line_get_extend(color_line)),
+        }
+    }
+}
+
+// The funcs `PaintFuncs::for_sink` installs.
+
+fn sink<'s, S>(paint_data: *mut libc::c_void) -> &'s mut S {
+    unsafe { &mut *(paint_data as *mut S) }
+}
+
+extern "C" fn push_transform_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                                paint_data: *mut libc::c_void,
+                                                xx: f32, yx: f32, xy: f32, yy: f32,
+                                                dx: f32, dy: f32,
+                                                _user_data: *mut libc::c_void) {
+    sink::<S>(paint_data).push_transform(xx, yx, xy, yy, dx, dy)
+}
+
+extern "C" fn pop_transform_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                               paint_data: *mut libc::c_void,
+                                               _user_data: *mut libc::c_void) {
+    sink::<S>(paint_data).pop_transform()
+}
+
+extern "C" fn push_clip_glyph_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                                 paint_data: *mut libc::c_void,
+                                                 glyph: Codepoint, font: *mut hb_font_t,
+                                                 _user_data: *mut libc::c_void) {
+    let font = ManuallyDrop::new(Font::from(font));
+    sink::<S>(paint_data).push_clip_glyph(glyph, &font)
+}
+
+extern "C" fn push_clip_rectangle_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                                     paint_data: *mut libc::c_void,
+                                                     xmin: f32, ymin: f32, xmax: f32, ymax: f32,
+                                                     _user_data: *mut libc::c_void) {
+    sink::<S>(paint_data).push_clip_rectangle(xmin, ymin, xmax, ymax)
+}
+
+extern "C" fn pop_clip_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                          paint_data: *mut libc::c_void,
+                                          _user_data: *mut libc::c_void) {
+    sink::<S>(paint_data).pop_clip()
+}
+
+extern "C" fn color_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                       paint_data: *mut libc::c_void,
+                                       is_foreground: hb_bool_t, color: Color,
+                                       _user_data: *mut libc::c_void) {
+    sink::<S>(paint_data).color(is_foreground != 0, color)
+}
+
+extern "C" fn image_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                       paint_data: *mut libc::c_void,
+                                       image: *mut hb_blob_t,
+                                       width: libc::c_uint, height: libc::c_uint,
+                                       format: Tag, slant: f32,
+                                       extents: *mut GlyphExtents,
+                                       _user_data: *mut libc::c_void) -> hb_bool_t {
+    let image = ManuallyDrop::new(Blob::from(image));
+    let extents = unsafe { extents.as_ref() };
+    sink::<S>(paint_data).image(&image, width, height, format, slant, extents) as hb_bool_t
+}
+
+extern "C" fn linear_gradient_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                                 paint_data: *mut libc::c_void,
+                                                 color_line: *mut hb_color_line_t,
+                                                 x0: f32, y0: f32, x1: f32, y1: f32,
+                                                 x2: f32, y2: f32,
+                                                 _user_data: *mut libc::c_void) {
+    let color_line = unsafe { ColorLine::from_raw(color_line) };
+    sink::<S>(paint_data).linear_gradient(&color_line, (x0, y0), (x1, y1), (x2, y2))
+}
+
+extern "C" fn radial_gradient_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                                 paint_data: *mut libc::c_void,
+                                                 color_line: *mut hb_color_line_t,
+                                                 x0: f32, y0: f32, r0: f32,
+                                                 x1: f32, y1: f32, r1: f32,
+                                                 _user_data: *mut libc::c_void) {
+    let color_line = unsafe { ColorLine::from_raw(color_line) };
+    sink::<S>(paint_data).radial_gradient(&color_line, (x0, y0), r0, (x1, y1), r1)
+}
+
+extern "C" fn sweep_gradient_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                                paint_data: *mut libc::c_void,
+                                                color_line: *mut hb_color_line_t,
+                                                x0: f32, y0: f32,
+                                                start_angle: f32, end_angle: f32,
+                                                _user_data: *mut libc::c_void) {
+    let color_line = unsafe { ColorLine::from_raw(color_line) };
+    sink::<S>(paint_data).sweep_gradient(&color_line, (x0, y0), start_angle, end_angle)
+}
+
+extern "C" fn push_group_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                            paint_data: *mut libc::c_void,
+                                            _user_data: *mut libc::c_void) {
+    sink::<S>(paint_data).push_group()
+}
+
+extern "C" fn pop_group_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                           paint_data: *mut libc::c_void,
+                                           mode: libc::c_uint,
+                                           _user_data: *mut libc::c_void) {
+    sink::<S>(paint_data).pop_group(CompositeMode::from_raw(mode))
+}
+
+extern "C" fn custom_palette_color_func<S: PaintSink>(_pfuncs: *mut hb_paint_funcs_t,
+                                                      paint_data: *mut libc::c_void,
+                                                      color_index: libc::c_uint,
+                                                      color: *mut Color,
+                                                      _user_data: *mut libc::c_void)
+                                                   -> hb_bool_t {
+    match sink::<S>(paint_data).custom_palette_color(color_index) {
+        Some(custom) => {
+            unsafe { *color = custom; }
+            1
+        },
+        None => 0,
+    }
+}
+
+// FFI:
+
+/// `hb_color_line_t`, which we only ever see behind a pointer, to collect into a `ColorLine`.
+enum hb_color_line_t { }
+
+// HarfBuzz passes extend and composite modes on from the font unchecked, so they arrive as integers
+// that may be out of range; like HarfBuzz’s own renderers, we treat those as the defaults.
+
+impl PaintExtend {
+    fn from_raw(extend: libc::c_uint) -> PaintExtend {
+        match extend {
+            1 => PaintExtend::Repeat,
+            2 => PaintExtend::Reflect,
+            _ => PaintExtend::Pad,
+        }
+    }
+}
+
+impl CompositeMode {
+    fn from_raw(mode: libc::c_uint) -> CompositeMode {
+        match mode {
+            0 => CompositeMode::Clear,
+            1 => CompositeMode::Src,
+            2 => CompositeMode::Dest,
+            4 => CompositeMode::DestOver,
+            5 => CompositeMode::SrcIn,
+            6 => CompositeMode::DestIn,
+            7 => CompositeMode::SrcOut,
+            8 => CompositeMode::DestOut,
+            9 => CompositeMode::SrcAtop,
+            10 => CompositeMode::DestAtop,
+            11 => CompositeMode::Xor,
+            12 => CompositeMode::Plus,
+            13 => CompositeMode::Screen,
+            14 => CompositeMode::Overlay,
+            15 => CompositeMode::Darken,
+            16 => CompositeMode::Lighten,
+            17 => CompositeMode::ColorDodge,
+            18 => CompositeMode::ColorBurn,
+            19 => CompositeMode::HardLight,
+            20 => CompositeMode::SoftLight,
+            21 => CompositeMode::Difference,
+            22 => CompositeMode::Exclusion,
+            23 => CompositeMode::Multiply,
+            24 => CompositeMode::HslHue,
+            25 => CompositeMode::HslSaturation,
+            26 => CompositeMode::HslColor,
+            27 => CompositeMode::HslLuminosity,
+            _ => CompositeMode::SrcOver,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+struct hb_color_stop_t {
+    offset: f32,
+    is_foreground: hb_bool_t,
+    color: Color,
+}
+
+type hb_paint_push_transform_func_t = Option<extern "C" fn(pfuncs: *mut hb_paint_funcs_t,
+                                                           paint_data: *mut libc::c_void,
+                                                           xx: f32, yx: f32, xy: f32, yy: f32,
+                                                           dx: f32, dy: f32,
+                                                           user_data: *mut libc::c_void)>;
+type hb_paint_func_t = Option<extern "C" fn(pfuncs: *mut hb_paint_funcs_t,
+                                            paint_data: *mut libc::c_void,
+                                            user_data: *mut libc::c_void)>;
+type hb_paint_pop_transform_func_t = hb_paint_func_t;
+type hb_paint_push_clip_glyph_func_t = Option<extern "C" fn(pfuncs: *mut hb_paint_funcs_t,
+                                                            paint_data: *mut libc::c_void,
+                                                            glyph: Codepoint,
+                                                            font: *mut hb_font_t,
+                                                            user_data: *mut libc::c_void)>;
+type hb_paint_push_clip_rectangle_func_t = Option<extern "C" fn(pfuncs: *mut hb_paint_funcs_t,
+                                                                paint_data: *mut libc::c_void,
+                                                                xmin: f32, ymin: f32,
+                                                                xmax: f32, ymax: f32,
+                                                                user_data: *mut libc::c_void)>;
+type hb_paint_pop_clip_func_t = hb_paint_func_t;
+type hb_paint_color_func_t = Option<extern "C" fn(pfuncs: *mut hb_paint_funcs_t,
+                                                  paint_data: *mut libc::c_void,
+                                                  is_foreground: hb_bool_t, color: Color,
+                                                  user_data: *mut libc::c_void)>;
+type hb_paint_image_func_t = Option<extern "C" fn(pfuncs: *mut hb_paint_funcs_t,
+                                                  paint_data: *mut libc::c_void,
+                                                  image: *mut hb_blob_t,
+                                                  width: libc::c_uint, height: libc::c_uint,
+                                                  format: Tag, slant: f32,
+                                                  extents: *mut GlyphExtents,
+                                                  user_data: *mut libc::c_void) -> hb_bool_t>;
+type hb_paint_linear_gradient_func_t = Option<extern "C" fn(pfuncs: *mut hb_paint_funcs_t,
+                                                            paint_data: *mut libc::c_void,
+                                                            color_line: *mut hb_color_line_t,
+                                                            x0: f32, y0: f32,
+                                                            x1: f32, y1: f32,
+                                                            x2: f32, y2: f32,
+                                                            user_data: *mut libc::c_void)>;
+type hb_paint_radial_gradient_func_t = Option<extern "C" fn(pfuncs: *mut hb_paint_funcs_t,
+                                                            paint_data: *mut libc::c_void,
+                                                            color_line: *mut hb_color_line_t,
+                                                            x0: f32, y0: f32, r0: f32,
+                                                            x1: f32, y1: f32, r1: f32,
+                                                            user_data: *mut libc::c_void)>;
+type hb_paint_sweep_gradient_func_t = Option<extern "C" fn(pfuncs: *mut hb_paint_funcs_t,
+                                                           paint_data: *mut libc::c_void,
+                                                           color_line: *mut hb_color_line_t,
+                                                           x0: f32, y0: f32,
+                                                           start_angle: f32, end_angle: f32,
+                                                           user_data: *mut libc::c_void)>;
+type hb_paint_push_group_func_t = hb_paint_func_t;
+type hb_paint_pop_group_func_t = Option<extern "C" fn(pfuncs: *mut hb_paint_funcs_t,
+                                                      paint_data: *mut libc::c_void,
+                                                      mode: libc::c_uint,
+                                                      user_data: *mut libc::c_void)>;
+type hb_paint_custom_palette_color_func_t = Option<extern "C" fn(pfuncs: *mut hb_paint_funcs_t,
+                                                                 paint_data: *mut libc::c_void,
+                                                                 color_index: libc::c_uint,
+                                                                 color: *mut Color,
+                                                                 user_data: *mut libc::c_void)
+                                                              -> hb_bool_t>;
+
+extern "C" {
+    fn hb_paint_funcs_create() -> *mut hb_paint_funcs_t;
+    fn hb_paint_funcs_set_push_transform_func(funcs: *mut hb_paint_funcs_t,
+                                              func: hb_paint_push_transform_func_t,
+                                              user_data: *mut libc::c_void,
+                                              destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_pop_transform_func(funcs: *mut hb_paint_funcs_t,
+                                             func: hb_paint_pop_transform_func_t,
+                                             user_data: *mut libc::c_void,
+                                             destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_push_clip_glyph_func(funcs: *mut hb_paint_funcs_t,
+                                               func: hb_paint_push_clip_glyph_func_t,
+                                               user_data: *mut libc::c_void,
+                                               destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_push_clip_rectangle_func(funcs: *mut hb_paint_funcs_t,
+                                                   func: hb_paint_push_clip_rectangle_func_t,
+                                                   user_data: *mut libc::c_void,
+                                                   destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_pop_clip_func(funcs: *mut hb_paint_funcs_t,
+                                        func: hb_paint_pop_clip_func_t,
+                                        user_data: *mut libc::c_void,
+                                        destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_color_func(funcs: *mut hb_paint_funcs_t,
+                                     func: hb_paint_color_func_t,
+                                     user_data: *mut libc::c_void,
+                                     destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_image_func(funcs: *mut hb_paint_funcs_t,
+                                     func: hb_paint_image_func_t,
+                                     user_data: *mut libc::c_void,
+                                     destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_linear_gradient_func(funcs: *mut hb_paint_funcs_t,
+                                               func: hb_paint_linear_gradient_func_t,
+                                               user_data: *mut libc::c_void,
+                                               destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_radial_gradient_func(funcs: *mut hb_paint_funcs_t,
+                                               func: hb_paint_radial_gradient_func_t,
+                                               user_data: *mut libc::c_void,
+                                               destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_sweep_gradient_func(funcs: *mut hb_paint_funcs_t,
+                                              func: hb_paint_sweep_gradient_func_t,
+                                              user_data: *mut libc::c_void,
+                                              destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_push_group_func(funcs: *mut hb_paint_funcs_t,
+                                          func: hb_paint_push_group_func_t,
+                                          user_data: *mut libc::c_void,
+                                          destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_pop_group_func(funcs: *mut hb_paint_funcs_t,
+                                         func: hb_paint_pop_group_func_t,
+                                         user_data: *mut libc::c_void,
+                                         destroy: hb_destroy_func_t);
+    fn hb_paint_funcs_set_custom_palette_color_func(funcs: *mut hb_paint_funcs_t,
+                                                    func: hb_paint_custom_palette_color_func_t,
+                                                    user_data: *mut libc::c_void,
+                                                    destroy: hb_destroy_func_t);
+
+    fn hb_color_line_get_color_stops(color_line: *mut hb_color_line_t, start: libc::c_uint,
+                                     count: *mut libc::c_uint /* in/out */,
+                                     color_stops: *mut hb_color_stop_t /* out */) -> libc::c_uint;
+    fn hb_color_line_get_extend(color_line: *mut hb_color_line_t) -> libc::c_uint;
+
+    fn hb_font_paint_glyph(font: *mut hb_font_t, glyph: Codepoint,
+                           pfuncs: *mut hb_paint_funcs_t, paint_data: *mut libc::c_void,
+                           palette_index: libc::c_uint, foreground: Color);
+}
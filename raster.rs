@@ -0,0 +1,395 @@
+//! Rasterizing glyph outlines into anti-aliased coverage bitmaps, so that text can be rendered
+//! with nothing but HarfBuzz and this crate. There is no equivalent in HarfBuzz.
+//!
+//! The rasterizer computes the exact area of each pixel covered by the outline (after flattening
+//! curves into lines), accumulating signed areas along each row: the non-zero rule, more or less,
+//! with coverage clamped at full where contours overlap.
+
+use std::cmp;
+
+use crate::buffer::Buffer;
+use crate::common::Codepoint;
+use crate::draw::DrawSink;
+use crate::font::Font;
+
+/// An 8-bit coverage (alpha) bitmap: 0 is untouched, 255 fully covered.
+///
+/// There is no equivalent in HarfBuzz.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bitmap {
+    pub width: u32,
+    pub height: u32,
+    /// Where the bitmap’s top left corner is relative to the origin, in whole pixels, with y
+    /// growing downwards (so `top` is negative for anything above the baseline).
+    pub left: i32,
+    pub top: i32,
+    /// The coverage values, a row at a time from the top, `width` to a row.
+    pub data: Vec<u8>,
+}
+
+impl Bitmap {
+    /// The coverage of the pixel at (`x`, `y`), counting from the top left corner of the bitmap.
+    ///
+    /// # Panics
+    ///
+    /// If the pixel is outside the bitmap.
+    pub fn get(&self, x: u32, y: u32) -> u8 {
+        assert!(x < self.width && y < self.height, "pixel ({}, {}) is outside a {}×{} bitmap",
+                x, y, self.width, self.height);
+        self.data[(y * self.width + x) as usize]
+    }
+}
+
+impl<'a> Font<'a> {
+    /// Rasterizes the glyph’s outline (see `draw_glyph`) at the font’s ppem (`Font::ppem`), with
+    /// the origin moved by `offset`, in pixels with y growing upwards, for subpixel positioning.
+    ///
+    /// The bitmap is just big enough for the outline; empty if there isn’t one. If the font has no
+    /// ppem set, its scale is taken to be in pixels.
+    ///
+    /// There is no equivalent in HarfBuzz.
+    pub fn rasterize_glyph(&self, glyph: Codepoint, offset: (f32, f32)) -> Bitmap {
+        let mut path = PixelPath::new(self.pixels_per_unit(), offset);
+        self.draw_glyph(glyph, &mut path);
+        path.rasterize()
+    }
+
+    /// Pixels per unit of the font’s scale, horizontally and vertically.
+    fn pixels_per_unit(&self) -> (f32, f32) {
+        let (x_scale, y_scale) = self.scale();
+        let (x_ppem, y_ppem) = match self.ppem() {
+            (0, 0) => return (1.0, 1.0),
+            (0, ppem) | (ppem, 0) => (ppem, ppem),
+            ppem => ppem,
+        };
+        let per_unit = |ppem: u32, scale: i32| {
+            if scale == 0 { 0.0 } else { ppem as f32 / scale as f32 }
+        };
+        (per_unit(x_ppem, x_scale), per_unit(y_ppem, y_scale))
+    }
+}
+
+/// Rasterizes a shaped buffer, its glyphs positioned by `glyph_positions()` along a line of text
+/// starting at the origin and rendered at the font’s ppem (see `Font::rasterize_glyph`). Positions
+/// aren’t rounded to whole pixels, so each glyph lands exactly where the shaper put it.
+///
+/// There is no equivalent in HarfBuzz.
+pub fn rasterize_buffer(font: &Font<'_>, buffer: &Buffer) -> Bitmap {
+    let pixels_per_unit = font.pixels_per_unit();
+    let mut path = PixelPath::new(pixels_per_unit, (0.0, 0.0));
+    let (mut pen_x, mut pen_y) = (0, 0);
+    for (info, position) in buffer.glyph_infos().iter().zip(buffer.glyph_positions()) {
+        path.offset = ((pen_x + position.x_offset) as f32 * pixels_per_unit.0,
+                       (pen_y + position.y_offset) as f32 * pixels_per_unit.1);
+        font.draw_glyph(info.codepoint, &mut path);
+        pen_x += position.x_advance;
+        pen_y += position.y_advance;
+    }
+    path.rasterize()
+}
+
+/// How far a flattened curve may stray from the real one, in pixels.
+const TOLERANCE: f32 = 0.05;
+
+/// Collects outlines in pixel space (y growing downwards), flattened into closed polygons, to find
+/// out how big the bitmap needs to be before rasterizing them.
+struct PixelPath {
+    pixels_per_unit: (f32, f32),
+    /// Where the origin goes, in pixels with y growing upwards.
+    offset: (f32, f32),
+    lines: Vec<((f32, f32), (f32, f32))>,
+    start: (f32, f32),
+    current: (f32, f32),
+}
+
+impl PixelPath {
+    fn new(pixels_per_unit: (f32, f32), offset: (f32, f32)) -> PixelPath {
+        PixelPath {
+            pixels_per_unit,
+            offset,
+            lines: Vec::new(),
+            start: (0.0, 0.0),
+            current: (0.0, 0.0),
+        }
+    }
+
+    fn to_pixels(&self, x: f32, y: f32) -> (f32, f32) {
+        (x * self.pixels_per_unit.0 + self.offset.0, -(y * self.pixels_per_unit.1 + self.offset.1))
+    }
+
+    fn line(&mut self, to: (f32, f32)) {
+        if to != self.current {
+            self.lines.push((self.current, to));
+        }
+        self.current = to;
+    }
+
+    fn rasterize(&self) -> Bitmap {
+        if self.lines.is_empty() {
+            return Bitmap::default();
+        }
+        let (mut min_x, mut min_y) = (f32::INFINITY, f32::INFINITY);
+        let (mut max_x, mut max_y) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+        for &(p, q) in &self.lines {
+            for &(x, y) in &[p, q] {
+                min_x = min_x.min(x);
+                min_y = min_y.min(y);
+                max_x = max_x.max(x);
+                max_y = max_y.max(y);
+            }
+        }
+        let left = min_x.floor() as i32;
+        let top = min_y.floor() as i32;
+        let width = cmp::max(max_x.ceil() as i32 - left, 1) as usize;
+        let height = cmp::max(max_y.ceil() as i32 - top, 1) as usize;
+
+        let mut canvas = Canvas::new(width, height);
+        for &((x0, y0), (x1, y1)) in &self.lines {
+            canvas.line((x0 - left as f32, y0 - top as f32), (x1 - left as f32, y1 - top as f32));
+        }
+        Bitmap {
+            width: width as u32,
+            height: height as u32,
+            left,
+            top,
+            data: canvas.coverage(),
+        }
+    }
+}
+
+impl DrawSink for PixelPath {
+    fn move_to(&mut self, to_x: f32, to_y: f32) {
+        self.close_path();
+        self.start = self.to_pixels(to_x, to_y);
+        self.current = self.start;
+    }
+
+    fn line_to(&mut self, to_x: f32, to_y: f32) {
+        let to = self.to_pixels(to_x, to_y);
+        self.line(to);
+    }
+
+    fn quad_to(&mut self, control_x: f32, control_y: f32, to_x: f32, to_y: f32) {
+        let p0 = self.current;
+        let p1 = self.to_pixels(control_x, control_y);
+        let p2 = self.to_pixels(to_x, to_y);
+        // The deviation from a straight line is at most a quarter of this.
+        let dd = hypot(p0.0 - 2.0 * p1.0 + p2.0, p0.1 - 2.0 * p1.1 + p2.1);
+        let n = segments(dd / (8.0 * TOLERANCE));
+        for i in 1..=n {
+            let t = i as f32 / n as f32;
+            let mt = 1.0 - t;
+            self.line((mt * mt * p0.0 + 2.0 * mt * t * p1.0 + t * t * p2.0,
+                       mt * mt * p0.1 + 2.0 * mt * t * p1.1 + t * t * p2.1));
+        }
+    }
+
+    fn cubic_to(&mut self, control1_x: f32, control1_y: f32, control2_x: f32, control2_y: f32,
+                to_x: f32, to_y: f32) {
+        let p0 = self.current;
+        let p1 = self.to_pixels(control1_x, control1_y);
+        let p2 = self.to_pixels(control2_x, control2_y);
+        let p3 = self.to_pixels(to_x, to_y);
+        let dd = f32::max(hypot(p0.0 - 2.0 * p1.0 + p2.0, p0.1 - 2.0 * p1.1 + p2.1),
+                          hypot(p1.0 - 2.0 * p2.0 + p3.0, p1.1 - 2.0 * p2.1 + p3.1));
+        let n = segments(3.0 * dd / (4.0 * TOLERANCE));
+        for i in 1..=n {
+            let t = i as f32 / n as f32;
+            let mt = 1.0 - t;
+            let (a, b, c, d) = (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+            self.line((a * p0.0 + b * p1.0 + c * p2.0 + d * p3.0,
+                       a * p0.1 + b * p1.1 + c * p2.1 + d * p3.1));
+        }
+    }
+
+    fn close_path(&mut self) {
+        let start = self.start;
+        self.line(start);
+    }
+}
+
+fn hypot(x: f32, y: f32) -> f32 {
+    (x * x + y * y).sqrt()
+}
+
+/// How many lines to flatten a curve into, given its deviation over the tolerance: the error goes
+/// down with the square of the number of lines.
+fn segments(deviation: f32) -> usize {
+    (deviation.sqrt().ceil() as usize).clamp(1, 100)
+}
+
+/// Signed area accumulation: each line adds how much of each pixel it covers to the pixel’s cell
+/// and takes the rest off the next one, so that summing each row from the left gives coverage.
+struct Canvas {
+    width: usize,
+    height: usize,
+    /// A row is `width + 1` cells long, the last taking what falls off the right hand side.
+    cells: Vec<f32>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Canvas {
+        Canvas { width, height, cells: vec![0.0; (width + 1) * height] }
+    }
+
+    fn line(&mut self, (x0, y0): (f32, f32), (x1, y1): (f32, f32)) {
+        if y0 == y1 {
+            return;
+        }
+        let (direction, (x0, y0), (x1, y1)) = if y0 < y1 {
+            (1.0, (x0, y0), (x1, y1))
+        } else {
+            (-1.0, (x1, y1), (x0, y0))
+        };
+        let dxdy = (x1 - x0) / (y1 - y0);
+        let first_row = cmp::max(y0.floor() as i32, 0) as usize;
+        let end_row = cmp::min(cmp::max(y1.ceil() as i32, 0) as usize, self.height);
+        for row in first_row..end_row {
+            let top = f32::max(y0, row as f32);
+            let bottom = f32::min(y1, (row + 1) as f32);
+            if bottom <= top {
+                continue;
+            }
+            let x_top = x0 + (top - y0) * dxdy;
+            let x_bottom = x0 + (bottom - y0) * dxdy;
+            self.row_piece(row, x_top, x_bottom, (bottom - top) * direction);
+        }
+    }
+
+    /// Accumulates the part of a line within one row, spanning `xa` to `xb` and `height` high
+    /// (negative if it goes upwards).
+    fn row_piece(&mut self, row: usize, xa: f32, xb: f32, height: f32) {
+        let width = self.width as f32;
+        let (xl, xr) = (f32::min(xa, xb).clamp(0.0, width), f32::max(xa, xb).clamp(0.0, width));
+        let cells = &mut self.cells[row * (self.width + 1)..(row + 1) * (self.width + 1)];
+        if xr - xl < 1e-6 {
+            let column = cmp::min(xl as usize, self.width - 1);
+            let fraction = xl - column as f32;
+            cells[column] += height * (1.0 - fraction);
+            cells[column + 1] += height * fraction;
+            return;
+        }
+        // Split the piece at pixel boundaries; within a pixel, the area to the right of it is a
+        // trapezoid, whose share of the pixel goes by its middle.
+        let mut x = xl;
+        while x < xr {
+            let column = cmp::min(x as usize, self.width - 1);
+            let next = f32::min((column + 1) as f32, xr);
+            let piece_height = height * (next - x) / (xr - xl);
+            let middle = (x + next) / 2.0 - column as f32;
+            cells[column] += piece_height * (1.0 - middle);
+            cells[column + 1] += piece_height * middle;
+            x = next;
+        }
+    }
+
+    fn coverage(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(self.width * self.height);
+        for row in self.cells.chunks(self.width + 1) {
+            let mut sum = 0.0;
+            for &cell in &row[..self.width] {
+                sum += cell;
+                data.push((f32::min(sum.abs(), 1.0) * 255.0).round() as u8);
+            }
+        }
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bitmap, Canvas, PixelPath, rasterize_buffer};
+    use crate::draw::DrawSink;
+    use crate::{Blob, Buffer, Face, Font, shape};
+
+    fn test_font() -> Font<'static> {
+        let data: &'static [u8] = include_bytes!("harfbuzz/src/wasm/sample/c/test.ttf");
+        let font = Font::new(Face::new(Blob::from(data), 0));
+        font.set_ppem(16, 16);
+        font
+    }
+
+    /// Fills a polygon given in pixel space on a canvas.
+    fn fill(canvas: &mut Canvas, points: &[(f32, f32)]) {
+        for (i, &p) in points.iter().enumerate() {
+            canvas.line(p, points[(i + 1) % points.len()]);
+        }
+    }
+
+    #[test]
+    fn pixel_aligned_square() {
+        let mut canvas = Canvas::new(4, 4);
+        fill(&mut canvas, &[(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)]);
+        let coverage = canvas.coverage();
+        for y in 0..4 {
+            for x in 0..4 {
+                let inside = (1..3).contains(&x) && (1..3).contains(&y);
+                assert_eq!(coverage[y * 4 + x], if inside { 255 } else { 0 }, "at ({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn half_covered_pixels() {
+        // The left half of the first pixel, and the lower right triangle of the second.
+        let mut canvas = Canvas::new(3, 1);
+        fill(&mut canvas, &[(0.0, 0.0), (0.5, 0.0), (0.5, 1.0), (0.0, 1.0)]);
+        fill(&mut canvas, &[(1.0, 1.0), (2.0, 0.0), (2.0, 1.0)]);
+        let coverage = canvas.coverage();
+        assert!((127..=128).contains(&coverage[0]), "{}", coverage[0]);
+        assert!((127..=128).contains(&coverage[1]), "{}", coverage[1]);
+        assert_eq!(coverage[2], 0);
+    }
+
+    #[test]
+    fn square_outline() {
+        // Font coordinates, y growing upwards, so the square is one to three pixels up.
+        let mut path = PixelPath::new((1.0, 1.0), (0.0, 0.0));
+        path.move_to(1.0, 1.0);
+        path.line_to(3.0, 1.0);
+        path.line_to(3.0, 3.0);
+        path.line_to(1.0, 3.0);
+        path.close_path();
+        assert_eq!(path.rasterize(), Bitmap {
+            width: 2,
+            height: 2,
+            left: 1,
+            top: -3,
+            data: vec![255; 4],
+        });
+    }
+
+    #[test]
+    fn empty_outline() {
+        assert_eq!(PixelPath::new((1.0, 1.0), (0.0, 0.0)).rasterize(), Bitmap::default());
+    }
+
+    #[test]
+    fn empty_buffer() {
+        assert_eq!(rasterize_buffer(&test_font(), &Buffer::new()), Bitmap::default());
+    }
+
+    #[test]
+    fn shaped_buffer() {
+        let font = test_font();
+        let mut buffer = Buffer::new();
+        buffer.extend("H", 0, -1);
+        buffer.guess_segment_properties();
+        shape(&font, &mut buffer, &[]);
+        let glyph = buffer.glyph_infos()[0].codepoint;
+        let bitmap = rasterize_buffer(&font, &buffer);
+        assert_eq!(bitmap, font.rasterize_glyph(glyph, (0.0, 0.0)));
+
+        buffer.clear_contents();
+        buffer.extend("Hello", 0, -1);
+        buffer.guess_segment_properties();
+        shape(&font, &mut buffer, &[]);
+        let bitmap = rasterize_buffer(&font, &buffer);
+        // Five glyphs’ worth of advances, about 37 pixels at 16 ppem, and the ascender of the “l”,
+        // about 12 pixels above the baseline.
+        assert!((30..=40).contains(&bitmap.width), "{}", bitmap.width);
+        assert!((11..=14).contains(&bitmap.height), "{}", bitmap.height);
+        assert!(bitmap.data.contains(&255));
+        assert!(bitmap.data.contains(&0));
+    }
+}
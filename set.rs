@@ -0,0 +1,409 @@
+//! Derived from `hb-set.h`.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+
+use crate::common::{Codepoint, hb_bool_t};
+
+define_boxed_type! {
+    /// A set of integers (glyph IDs, code points, lookup indexes, &c.), as HarfBuzz takes and
+    /// returns them. Any `u32` but `u32::MAX` can be in one.
+    ///
+    /// As with the other HarfBuzz types, cloning a set makes another handle on the same set, so
+    /// that changes through one show through the other; use `copy` for a separate one.
+    struct Set;
+    enum hb_set_t;
+    fn hb_set_get_empty;
+    fn hb_set_reference;
+    fn hb_set_destroy;
+    fn hb_set_set_user_data;
+    fn hb_set_get_user_data;
+    // Sets don’t have make_immutable and is_immutable.
+}
+
+/// Equivalent to `HB_SET_VALUE_INVALID`, which is never in a set.
+const SET_VALUE_INVALID: Codepoint = u32::MAX;
+
+impl Set {
+    /// Equivalent to `hb_set_create`.
+    pub fn new() -> Set {
+        unsafe {
+            Set::from(hb_set_create())
+        }
+    }
+
+    /// A new set with the same contents.
+    ///
+    /// Equivalent to `hb_set_copy`.
+    pub fn copy(&self) -> Set {
+        unsafe {
+            Set::from(hb_set_copy(self.ptr))
+        }
+    }
+
+    /// Whether every change to the set so far has been able to allocate what it needed. Once one
+    /// hasn’t, the set is empty and stays that way.
+    ///
+    /// Equivalent to `hb_set_allocation_successful`.
+    pub fn allocation_successful(&self) -> bool {
+        unsafe {
+            hb_set_allocation_successful(self.ptr) != 0
+        }
+    }
+
+    /// Equivalent to `hb_set_clear`.
+    pub fn clear(&mut self) {
+        unsafe {
+            hb_set_clear(self.ptr)
+        }
+    }
+
+    /// Equivalent to `hb_set_is_empty`.
+    pub fn is_empty(&self) -> bool {
+        unsafe {
+            hb_set_is_empty(self.ptr) != 0
+        }
+    }
+
+    /// How many values are in the set.
+    ///
+    /// Equivalent to `hb_set_get_population`.
+    pub fn len(&self) -> u32 {
+        unsafe {
+            hb_set_get_population(self.ptr)
+        }
+    }
+
+    /// Equivalent to `hb_set_has`.
+    pub fn contains(&self, value: u32) -> bool {
+        unsafe {
+            hb_set_has(self.ptr, value) != 0
+        }
+    }
+
+    /// Adds a value to the set, returning whether it wasn’t there already.
+    ///
+    /// Equivalent to `hb_set_add`.
+    pub fn insert(&mut self, value: u32) -> bool {
+        if self.contains(value) {
+            return false;
+        }
+        unsafe {
+            hb_set_add(self.ptr, value);
+        }
+        self.contains(value)
+    }
+
+    /// Removes a value from the set, returning whether it was there.
+    ///
+    /// Equivalent to `hb_set_del`.
+    pub fn remove(&mut self, value: u32) -> bool {
+        let removed = self.contains(value);
+        unsafe {
+            hb_set_del(self.ptr, value);
+        }
+        removed
+    }
+
+    /// Adds every value in the range, e.g. `set.insert_range(0x600..0x700)`.
+    ///
+    /// Equivalent to `hb_set_add_range`.
+    pub fn insert_range<R: RangeBounds<u32>>(&mut self, range: R) {
+        if let Some((first, last)) = inclusive_bounds(range) {
+            unsafe {
+                hb_set_add_range(self.ptr, first, last);
+            }
+        }
+    }
+
+    /// Removes every value in the range.
+    ///
+    /// Equivalent to `hb_set_del_range`.
+    pub fn remove_range<R: RangeBounds<u32>>(&mut self, range: R) {
+        if let Some((first, last)) = inclusive_bounds(range) {
+            unsafe {
+                hb_set_del_range(self.ptr, first, last);
+            }
+        }
+    }
+
+    /// The smallest value in the set, if it isn’t empty.
+    ///
+    /// Equivalent to `hb_set_get_min`.
+    pub fn min(&self) -> Option<u32> {
+        valid(unsafe { hb_set_get_min(self.ptr) })
+    }
+
+    /// The largest value in the set, if it isn’t empty.
+    ///
+    /// Equivalent to `hb_set_get_max`.
+    pub fn max(&self) -> Option<u32> {
+        valid(unsafe { hb_set_get_max(self.ptr) })
+    }
+
+    /// Whether every value in this set is also in `other`.
+    ///
+    /// Equivalent to `hb_set_is_subset`.
+    pub fn is_subset(&self, other: &Set) -> bool {
+        unsafe {
+            hb_set_is_subset(self.ptr, other.ptr) != 0
+        }
+    }
+
+    /// Adds every value in `other` to this set.
+    ///
+    /// Equivalent to `hb_set_union`.
+    pub fn union(&mut self, other: &Set) {
+        unsafe {
+            hb_set_union(self.ptr, other.ptr)
+        }
+    }
+
+    /// Removes every value not in `other` from this set.
+    ///
+    /// Equivalent to `hb_set_intersect`.
+    pub fn intersect(&mut self, other: &Set) {
+        unsafe {
+            hb_set_intersect(self.ptr, other.ptr)
+        }
+    }
+
+    /// Removes every value in `other` from this set.
+    ///
+    /// Equivalent to `hb_set_subtract`.
+    pub fn subtract(&mut self, other: &Set) {
+        unsafe {
+            hb_set_subtract(self.ptr, other.ptr)
+        }
+    }
+
+    /// Leaves this set with the values in it or in `other`, but not both.
+    ///
+    /// Equivalent to `hb_set_symmetric_difference`.
+    pub fn symmetric_difference(&mut self, other: &Set) {
+        unsafe {
+            hb_set_symmetric_difference(self.ptr, other.ptr)
+        }
+    }
+
+    /// Turns the set into its complement: every value that wasn’t in it is, and vice versa. This
+    /// is cheap, since HarfBuzz just remembers that the set is inverted; but iterating over the
+    /// inverse of a small set takes a very long time.
+    ///
+    /// Equivalent to `hb_set_invert`.
+    pub fn invert(&mut self) {
+        unsafe {
+            hb_set_invert(self.ptr)
+        }
+    }
+
+    /// Equivalent to `hb_set_is_inverted`.
+    pub fn is_inverted(&self) -> bool {
+        unsafe {
+            hb_set_is_inverted(self.ptr) != 0
+        }
+    }
+
+    /// The values in the set, in increasing order.
+    ///
+    /// Equivalent to `hb_set_next`.
+    pub fn iter(&self) -> SetIter<'_> {
+        SetIter { set: self, value: Some(SET_VALUE_INVALID) }
+    }
+
+    /// The runs of consecutive values in the set, in increasing order, e.g. `0x600..=0x6FF`.
+    ///
+    /// Equivalent to `hb_set_next_range`.
+    pub fn ranges(&self) -> SetRanges<'_> {
+        SetRanges { set: self, last: Some(SET_VALUE_INVALID) }
+    }
+}
+
+impl Default for Set {
+    fn default() -> Set {
+        Set::new()
+    }
+}
+
+/// Equivalent to `hb_set_is_equal`.
+impl PartialEq for Set {
+    fn eq(&self, other: &Set) -> bool {
+        unsafe {
+            hb_set_is_equal(self.ptr, other.ptr) != 0
+        }
+    }
+}
+
+impl Eq for Set {}
+
+/// Equivalent to `hb_set_hash`.
+impl Hash for Set {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        unsafe {
+            hb_set_hash(self.ptr).hash(state)
+        }
+    }
+}
+
+impl fmt::Debug for Set {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.ranges()).finish()
+    }
+}
+
+impl FromIterator<u32> for Set {
+    fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> Set {
+        let mut set = Set::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<u32> for Set {
+    fn extend<I: IntoIterator<Item = u32>>(&mut self, iter: I) {
+        for value in iter {
+            unsafe {
+                hb_set_add(self.ptr, value);
+            }
+        }
+    }
+}
+
+impl<'s> Extend<&'s u32> for Set {
+    fn extend<I: IntoIterator<Item = &'s u32>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<'s> IntoIterator for &'s Set {
+    type Item = u32;
+    type IntoIter = SetIter<'s>;
+
+    fn into_iter(self) -> SetIter<'s> {
+        self.iter()
+    }
+}
+
+impl IntoIterator for Set {
+    type Item = u32;
+    type IntoIter = SetIntoIter;
+
+    fn into_iter(self) -> SetIntoIter {
+        SetIntoIter { set: self, value: Some(SET_VALUE_INVALID) }
+    }
+}
+
+/// The values in a set: see `Set::iter`.
+pub struct SetIter<'s> {
+    set: &'s Set,
+    /// The last value returned, `None` once there are no more.
+    value: Option<Codepoint>,
+}
+
+impl<'s> Iterator for SetIter<'s> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        next(self.set, &mut self.value)
+    }
+}
+
+/// The values in a set, which it owns: see `Set::into_iter`.
+pub struct SetIntoIter {
+    set: Set,
+    value: Option<Codepoint>,
+}
+
+impl Iterator for SetIntoIter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        next(&self.set, &mut self.value)
+    }
+}
+
+/// The runs of consecutive values in a set: see `Set::ranges`.
+pub struct SetRanges<'s> {
+    set: &'s Set,
+    /// The end of the last range returned, `None` once there are no more.
+    last: Option<Codepoint>,
+}
+
+impl<'s> Iterator for SetRanges<'s> {
+    type Item = RangeInclusive<u32>;
+
+    fn next(&mut self) -> Option<RangeInclusive<u32>> {
+        // HarfBuzz starts over after the end, so we stop there.
+        let (mut first, mut last) = (SET_VALUE_INVALID, self.last?);
+        unsafe {
+            if hb_set_next_range(self.set.ptr, &mut first, &mut last) != 0 {
+                self.last = Some(last);
+                Some(first..=last)
+            } else {
+                self.last = None;
+                None
+            }
+        }
+    }
+}
+
+fn next(set: &Set, value: &mut Option<Codepoint>) -> Option<u32> {
+    // HarfBuzz starts over after the end, so we stop there.
+    let mut next = (*value)?;
+    unsafe {
+        *value = if hb_set_next(set.ptr, &mut next) != 0 { Some(next) } else { None };
+    }
+    *value
+}
+
+fn valid(value: Codepoint) -> Option<u32> {
+    if value == SET_VALUE_INVALID { None } else { Some(value) }
+}
+
+/// The first and last values in a range, unless it’s empty, leaving out `SET_VALUE_INVALID`.
+fn inclusive_bounds<R: RangeBounds<u32>>(range: R) -> Option<(u32, u32)> {
+    let first = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let last = match range.end_bound() {
+        Bound::Included(&end) => end,
+        Bound::Excluded(&end) => end.checked_sub(1)?,
+        Bound::Unbounded => SET_VALUE_INVALID,
+    };
+    let last = last.min(SET_VALUE_INVALID - 1);
+    if first <= last { Some((first, last)) } else { None }
+}
+
+// FFI:
+
+extern "C" {
+    fn hb_set_create() -> *mut hb_set_t;
+    fn hb_set_copy(set: *mut hb_set_t) -> *mut hb_set_t;
+    fn hb_set_allocation_successful(set: *mut hb_set_t) -> hb_bool_t;
+    fn hb_set_clear(set: *mut hb_set_t);
+    fn hb_set_is_empty(set: *mut hb_set_t) -> hb_bool_t;
+    fn hb_set_invert(set: *mut hb_set_t);
+    fn hb_set_is_inverted(set: *mut hb_set_t) -> hb_bool_t;
+    fn hb_set_has(set: *mut hb_set_t, codepoint: Codepoint) -> hb_bool_t;
+    fn hb_set_add(set: *mut hb_set_t, codepoint: Codepoint);
+    fn hb_set_add_range(set: *mut hb_set_t, first: Codepoint, last: Codepoint);
+    fn hb_set_del(set: *mut hb_set_t, codepoint: Codepoint);
+    fn hb_set_del_range(set: *mut hb_set_t, first: Codepoint, last: Codepoint);
+    fn hb_set_is_equal(set: *mut hb_set_t, other: *mut hb_set_t) -> hb_bool_t;
+    fn hb_set_hash(set: *mut hb_set_t) -> libc::c_uint;
+    fn hb_set_is_subset(set: *mut hb_set_t, larger_set: *mut hb_set_t) -> hb_bool_t;
+    fn hb_set_union(set: *mut hb_set_t, other: *mut hb_set_t);
+    fn hb_set_intersect(set: *mut hb_set_t, other: *mut hb_set_t);
+    fn hb_set_subtract(set: *mut hb_set_t, other: *mut hb_set_t);
+    fn hb_set_symmetric_difference(set: *mut hb_set_t, other: *mut hb_set_t);
+    fn hb_set_get_population(set: *mut hb_set_t) -> libc::c_uint;
+    fn hb_set_get_min(set: *mut hb_set_t) -> Codepoint;
+    fn hb_set_get_max(set: *mut hb_set_t) -> Codepoint;
+    fn hb_set_next(set: *mut hb_set_t, codepoint: *mut Codepoint) -> hb_bool_t;
+    fn hb_set_next_range(set: *mut hb_set_t, first: *mut Codepoint,
+                         last: *mut Codepoint) -> hb_bool_t;
+}
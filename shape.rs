@@ -6,11 +6,9 @@ use std::os::raw::c_char;
 use std::ptr;
 use std::str::FromStr;
 
-use libc;
-
-use harfbuzz::common::{Tag, hb_bool_t};
-use harfbuzz::buffer::{Buffer, hb_buffer_t};
-use harfbuzz::font::{Font, hb_font_t};
+use crate::common::{Tag, hb_bool_t};
+use crate::buffer::{Buffer, hb_buffer_t};
+use crate::font::{Font, hb_font_t};
 
 /// Equivalent to `hb_feature_t`.
 #[derive(Copy, Clone, Debug, Default)]
@@ -40,13 +38,13 @@ impl FromStr for Feature {
 
 // TODO: implement Display with hb_feature_to_string. Step one is knowing how much buffer we need!
 
-pub fn shape(font: &Font, buffer: &mut Buffer, features: &[Feature]) {
+pub fn shape(font: &Font<'_>, buffer: &mut Buffer, features: &[Feature]) {
     unsafe {
         hb_shape(font.ptr, buffer.ptr, features.as_ptr(), features.len() as u32);
     }
 }
 
-pub fn shape_full(font: &Font, buffer: &mut Buffer, features: &[Feature],
+pub fn shape_full(font: &Font<'_>, buffer: &mut Buffer, features: &[Feature],
                   shaper_list: &ShaperList) -> bool {
     unsafe {
         hb_shape_full(font.ptr, buffer.ptr, features.as_ptr(), features.len() as u32,
@@ -69,7 +67,7 @@ pub struct ShaperList {
 /// A convenience conversion: `["foo", "bar"].into()`.
 impl<'a, 'b> From<&'a [&'b str]> for ShaperList {
     fn from(slice: &'a [&'b str]) -> ShaperList {
-        slice.iter().map(|&str| str).collect()
+        slice.iter().copied().collect()
     }
 }
 
@@ -78,10 +76,10 @@ impl<'a, 'b> From<&'a [&'b str]> for ShaperList {
 impl<'a> FromIterator<&'a str> for ShaperList {
     fn from_iter<T>(iter: T) -> ShaperList
     where T: IntoIterator<Item = &'a str> {
-        let mut iterator = iter.into_iter();
+        let iterator = iter.into_iter();
         let (lower, _) = iterator.size_hint();
         let mut vec = Vec::with_capacity(lower.saturating_add(1));
-        vec.extend(iterator.map(|str| CString::new(str).unwrap_or(CString::default()).into_raw()
+        vec.extend(iterator.map(|str| CString::new(str).unwrap_or_default().into_raw()
                                       as *const c_char));
         vec.push(ptr::null());
         ShaperList {
@@ -0,0 +1,226 @@
+//! Rendering shaped text as SVG, for looking at (and comparing) shaping results without a
+//! rasterizer. There is no equivalent in HarfBuzz; it’s after what `hb-view` does with cairo.
+
+use std::cmp;
+use std::fmt::Write;
+
+use crate::buffer::Buffer;
+use crate::common::Position;
+use crate::draw::DrawSink;
+use crate::font::Font;
+
+/// What `render_svg` draws besides the glyphs themselves.
+///
+/// There is no equivalent in HarfBuzz.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SvgOptions {
+    /// Draw a dashed line across the text wherever a new cluster starts (and at the end).
+    pub clusters: bool,
+    /// Mark each glyph’s origin, i.e. where it was positioned, with a dot.
+    pub origins: bool,
+    /// Outline each glyph’s ink box, from `Font::get_glyph_extents`.
+    pub extents: bool,
+}
+
+/// Renders a shaped buffer as a standalone SVG document, in the font’s scale (`Font::scale`): one
+/// `<path>` per glyph, positioned by `glyph_positions()` as it would be along a line of text
+/// starting at the origin, plus whatever `options` ask for.
+///
+/// The document is sized to fit the line (from the font’s extents for the buffer’s direction) and
+/// the glyphs’ ink, with a little margin. Set a smaller scale on the font for a smaller image.
+///
+/// There is no equivalent in HarfBuzz.
+pub fn render_svg(font: &Font<'_>, buffer: &Buffer, options: &SvgOptions) -> String {
+    let direction = buffer.direction();
+    let vertical = direction.is_vertical();
+    let line_extents = font.extents_for_direction(direction);
+    let (cross_min, cross_max) = (cmp::min(line_extents.descender, line_extents.ascender),
+                                  cmp::max(line_extents.descender, line_extents.ascender));
+
+    // Work out where everything goes first, so that we know how big the document is.
+    let mut bounds = Bounds::new();
+    let mut pen = (0, 0);
+    let mut origins = Vec::with_capacity(buffer.len() as usize);
+    let mut cluster_starts = Vec::new();
+    let mut previous_cluster = None;
+    for (info, position) in buffer.glyph_infos().iter().zip(buffer.glyph_positions()) {
+        if previous_cluster != Some(info.cluster) {
+            cluster_starts.push(pen);
+            previous_cluster = Some(info.cluster);
+        }
+        let origin = (pen.0 + position.x_offset, pen.1 + position.y_offset);
+        let extents = font.get_glyph_extents(info.codepoint);
+        if let Some(extents) = extents {
+            bounds.add(origin.0 + extents.x_bearing, origin.1 + extents.y_bearing);
+            bounds.add(origin.0 + extents.x_bearing + extents.width,
+                       origin.1 + extents.y_bearing + extents.height);
+        }
+        origins.push((origin, extents));
+        pen = (pen.0 + position.x_advance, pen.1 + position.y_advance);
+    }
+    cluster_starts.push(pen);
+    for &(x, y) in &[(0, 0), pen] {
+        if vertical {
+            bounds.add(x + cross_min, y);
+            bounds.add(x + cross_max, y);
+        } else {
+            bounds.add(x, y + cross_min);
+            bounds.add(x, y + cross_max);
+        }
+    }
+
+    let (x_scale, y_scale) = font.scale();
+    let em = cmp::max(x_scale.abs(), y_scale.abs());
+    let margin = em / 20;
+    // SVG’s y grows downwards, so everything’s y is negated: the top is at −max_y.
+    let (left, top) = (bounds.min_x - margin, -bounds.max_y - margin);
+    let (width, height) = (bounds.max_x - bounds.min_x + 2 * margin,
+                           bounds.max_y - bounds.min_y + 2 * margin);
+    let stroke_width = cmp::max(em / 200, 1);
+
+    let mut svg = String::new();
+    let _ = writeln!(svg, "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+                           viewBox=\"{} {} {} {}\" width=\"{}\" height=\"{}\">",
+                     left, top, width, height, width, height);
+
+    if options.extents {
+        let _ = writeln!(svg, "<g fill=\"none\" stroke=\"#4a90d9\" stroke-width=\"{}\">",
+                         stroke_width);
+        for &((x, y), extents) in &origins {
+            if let Some(extents) = extents.filter(|e| e.width != 0 || e.height != 0) {
+                // Height is negative, going down from the top.
+                let _ = writeln!(svg, "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>",
+                                 x + extents.x_bearing, -(y + extents.y_bearing),
+                                 extents.width, -extents.height);
+            }
+        }
+        svg.push_str("</g>\n");
+    }
+
+    svg.push_str("<g fill=\"black\">\n");
+    for (info, &((x, y), _)) in buffer.glyph_infos().iter().zip(&origins) {
+        let mut path = SvgPath(String::new());
+        font.draw_glyph(info.codepoint, &mut path);
+        let _ = writeln!(svg, "<path data-glyph=\"{}\" data-cluster=\"{}\" \
+                               transform=\"translate({} {})\" d=\"{}\"/>",
+                         info.codepoint, info.cluster, x, -y, path.0.trim_end());
+    }
+    svg.push_str("</g>\n");
+
+    if options.clusters {
+        let _ = writeln!(svg, "<g stroke=\"#d0021b\" stroke-width=\"{}\" \
+                               stroke-dasharray=\"{} {}\">",
+                         stroke_width, 4 * stroke_width, 4 * stroke_width);
+        for &(x, y) in &cluster_starts {
+            let (x1, y1, x2, y2) = if vertical {
+                (x + cross_min, y, x + cross_max, y)
+            } else {
+                (x, y + cross_min, x, y + cross_max)
+            };
+            let _ = writeln!(svg, "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"/>",
+                             x1, -y1, x2, -y2);
+        }
+        svg.push_str("</g>\n");
+    }
+
+    if options.origins {
+        svg.push_str("<g fill=\"#f5a623\">\n");
+        for &((x, y), _) in &origins {
+            let _ = writeln!(svg, "<circle cx=\"{}\" cy=\"{}\" r=\"{}\"/>",
+                             x, -y, 3 * stroke_width);
+        }
+        svg.push_str("</g>\n");
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// The box everything drawn has to fit in, in font coordinates (y growing upwards).
+struct Bounds {
+    min_x: Position,
+    min_y: Position,
+    max_x: Position,
+    max_y: Position,
+}
+
+impl Bounds {
+    fn new() -> Bounds {
+        Bounds { min_x: 0, min_y: 0, max_x: 0, max_y: 0 }
+    }
+
+    fn add(&mut self, x: Position, y: Position) {
+        self.min_x = cmp::min(self.min_x, x);
+        self.min_y = cmp::min(self.min_y, y);
+        self.max_x = cmp::max(self.max_x, x);
+        self.max_y = cmp::max(self.max_y, y);
+    }
+}
+
+/// Path data for a `<path>`’s `d` attribute, with y negated for SVG.
+struct SvgPath(String);
+
+impl DrawSink for SvgPath {
+    fn move_to(&mut self, to_x: f32, to_y: f32) {
+        let _ = write!(self.0, "M{} {} ", to_x, flip(to_y));
+    }
+
+    fn line_to(&mut self, to_x: f32, to_y: f32) {
+        let _ = write!(self.0, "L{} {} ", to_x, flip(to_y));
+    }
+
+    fn quad_to(&mut self, control_x: f32, control_y: f32, to_x: f32, to_y: f32) {
+        let _ = write!(self.0, "Q{} {} {} {} ", control_x, flip(control_y), to_x, flip(to_y));
+    }
+
+    fn cubic_to(&mut self, control1_x: f32, control1_y: f32, control2_x: f32, control2_y: f32,
+                to_x: f32, to_y: f32) {
+        let _ = write!(self.0, "C{} {} {} {} {} {} ", control1_x, flip(control1_y),
+                       control2_x, flip(control2_y), to_x, flip(to_y));
+    }
+
+    fn close_path(&mut self) {
+        self.0.push_str("Z ");
+    }
+}
+
+/// Negates y for SVG, without making any “-0”s.
+fn flip(y: f32) -> f32 {
+    0.0 - y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SvgOptions, render_svg};
+    use crate::{Blob, Buffer, Face, Font, shape};
+
+    fn test_font() -> Font<'static> {
+        let data: &'static [u8] = include_bytes!("harfbuzz/src/wasm/sample/c/test.ttf");
+        Font::new(Face::new(Blob::from(data), 0))
+    }
+
+    #[test]
+    fn empty_buffer() {
+        let options = SvgOptions { clusters: true, origins: true, extents: true };
+        let svg = render_svg(&test_font(), &Buffer::new(), &options);
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(!svg.contains("<path"));
+    }
+
+    #[test]
+    fn shaped_buffer() {
+        let font = test_font();
+        let mut buffer = Buffer::new();
+        buffer.extend("Hello", 0, -1);
+        buffer.guess_segment_properties();
+        shape(&font, &mut buffer, &[]);
+        let options = SvgOptions { clusters: true, origins: true, extents: false };
+        let svg = render_svg(&font, &buffer, &options);
+        assert_eq!(svg.matches("<path ").count(), 5);
+        assert_eq!(svg.matches("<circle ").count(), 5);
+        // One line at the start of each cluster, and one at the end.
+        assert_eq!(svg.matches("<line ").count(), 6);
+        assert!(svg.contains("data-glyph=\"44\" data-cluster=\"0\" transform=\"translate(0 0)\""));
+    }
+}
@@ -1,7 +1,6 @@
 //! Derived from hb-unicode.rs
 
 use std::mem;
-use libc;
 
 /// Unicode Character Database property: General_Category (gc)
 ///
@@ -170,7 +169,7 @@ impl From<hb_unicode_combining_class_t> for UnicodeCombiningClass {
             0 | 1 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 |
             23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 84 | 91 | 103 |
             107 | 118 | 122 | 129 | 130 | 132 | 200 | 202 | 214 | 216 | 218 | 220 | 222 | 224 |
-            226 | 228 | 230 | 232 | 233 | 234 | 240 | 255 => unsafe { mem::transmute(ucc) },
+            226 | 228 | 230 | 232 | 233 | 234 | 240 | 255 => unsafe { mem::transmute::<u32, UnicodeCombiningClass>(ucc) },
             _ => UnicodeCombiningClass::__Nonexhaustive,
         }
     }